@@ -1,3 +1,5 @@
+use crate::Nil;
+
 /// Heterogenous list with head and tail values, where tail is another heterogenous list.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
 pub struct Cons<Head, Tail>(pub Head, pub Tail)
@@ -52,6 +54,27 @@ impl<Head, Tail> Cons<Head, Tail> {
     }
 }
 
+impl<Head> Cons<Head, Nil> {
+    /// Constructs a new heterogenous list with exactly one element.
+    ///
+    /// This is the same as `hlist![value]`, but can be used where a macro is awkward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, Cons, Nil};
+    ///
+    /// let list: Cons<i32, Nil> = Cons::single(42);
+    /// assert_eq!(list, hlist![42]);
+    ///
+    /// const LIST: Cons<i32, Nil> = Cons::single(42);
+    /// assert_eq!(LIST, hlist![42]);
+    /// ```
+    pub const fn single(value: Head) -> Self {
+        Self(value, Nil)
+    }
+}
+
 impl<Head, Tail> Cons<Head, Tail>
 where
     Tail: ?Sized,
@@ -119,6 +142,43 @@ where
         let Self(_, tail) = self;
         tail
     }
+
+    /// Borrows head and tail values of self by reference at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::Cons;
+    ///
+    /// let list = Cons::new(1, "hello world");
+    /// let (head, tail) = list.split_head_ref();
+    /// assert_eq!(head, &1);
+    /// assert_eq!(tail, &"hello world");
+    /// ```
+    pub const fn split_head_ref(&self) -> (&Head, &Tail) {
+        let Self(head, tail) = self;
+        (head, tail)
+    }
+
+    /// Borrows head and tail values of self by mutable reference at once.
+    ///
+    /// Head and tail are disjoint, so both of them can be mutated independently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, Cons};
+    ///
+    /// let mut list = Cons::new(1, hlist![2.0, true]);
+    /// let (head, tail) = list.split_head_mut();
+    /// *head += 1;
+    /// *tail.head_mut() *= 2.0;
+    /// assert_eq!(list, hlist![2, 4.0, true]);
+    /// ```
+    pub fn split_head_mut(&mut self) -> (&mut Head, &mut Tail) {
+        let Self(head, tail) = self;
+        (head, tail)
+    }
 }
 
 impl<Head, Tail> AsRef<Cons<Head, Tail>> for Cons<Head, Tail>
@@ -40,7 +40,7 @@
 
 use core::iter::FusedIterator;
 
-use crate::{ops::ToRef, Cons, HList, Nil};
+use crate::{ops::ToRef, Cons, HList, Len, Nil};
 
 use self::impl_details::{PrepareIter, ReadyIter};
 
@@ -70,6 +70,64 @@ where
     prepared: T::Output,
 }
 
+impl<T> IntoIter<T>
+where
+    T: PrepareIter,
+{
+    /// Returns the exact count of elements remaining in the iterator.
+    ///
+    /// This is the same as [`ExactSizeIterator::len`],
+    /// but does not require the trait to be imported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::hlist;
+    ///
+    /// let mut iter = hlist![1, 2, 3].into_iter();
+    /// assert_eq!(iter.remaining(), 3);
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.remaining(), 2);
+    /// assert_eq!(iter.size_hint(), (2, Some(2)));
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    /// Creates an iterator which pairs each element
+    /// with the count of elements remaining after it was yielded.
+    ///
+    /// This is useful for progress reporting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::hlist;
+    ///
+    /// let mut iter = hlist![1, 2, 3].into_iter().progress();
+    /// assert_eq!(iter.next(), Some((2, 1)));
+    /// assert_eq!(iter.next(), Some((1, 2)));
+    /// assert_eq!(iter.next(), Some((0, 3)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let done: Vec<_> = hlist![1, 2, 3, 4]
+    ///     .into_iter()
+    ///     .progress()
+    ///     .filter(|(_, item)| item % 2 == 0)
+    ///     .map(|(remaining, _)| remaining)
+    ///     .collect();
+    /// assert_eq!(done, [2, 0]);
+    /// ```
+    pub fn progress(self) -> impl Iterator<Item = (usize, <Self as Iterator>::Item)> {
+        let mut iter = self;
+        core::iter::from_fn(move || {
+            let item = iter.next()?;
+            Some((iter.len(), item))
+        })
+    }
+}
+
 impl<T> Iterator for IntoIter<T>
 where
     T: PrepareIter,
@@ -253,6 +311,120 @@ where
 {
 }
 
+/// Convert homogenous heterogenous list into an [array iterator](core::array::IntoIter).
+pub trait IntoArrayIter: Homogenous + Len {
+    /// Converts homogenous heterogenous list into an [array iterator](core::array::IntoIter).
+    ///
+    /// Elements of the list are moved into an array of length `N` first,
+    /// so the returned iterator provides all the guarantees of the standard one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, iter::IntoArrayIter};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let mut iter = list.into_array_iter::<3>();
+    /// assert_eq!(iter.as_slice(), &[1, 2, 3]);
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Length of an array must be equal to the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, iter::IntoArrayIter};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let iter = list.into_array_iter::<4>();
+    /// ```
+    fn into_array_iter<const N: usize>(self) -> core::array::IntoIter<Self::Item, N>;
+}
+
+impl<T> IntoArrayIter for T
+where
+    T: Homogenous + Len,
+{
+    fn into_array_iter<const N: usize>(self) -> core::array::IntoIter<Self::Item, N> {
+        const {
+            assert!(
+                N == T::LEN,
+                "length of an array must be equal to the list length"
+            )
+        };
+
+        let mut iter = self.into_iter();
+        let array = core::array::from_fn(|_| iter.next().expect("length was checked before"));
+        array.into_iter()
+    }
+}
+
+/// Create heterogenous list from an iterator, taking exactly the needed count of elements.
+pub trait TakeFromIter<A>: HList + Sized {
+    /// Creates a new heterogenous list by taking elements from an input iterator.
+    ///
+    /// Exactly [`Len::LEN`] elements are taken from the iterator, and the rest is ignored.
+    /// Unlike [`FromIterator`] implementation, this function does not panic.
+    ///
+    /// Returns [`None`] if there is not enough elements in the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, iter::TakeFromIter, HList};
+    ///
+    /// let list = <HList![i32, i32]>::take_from_iter(0..);
+    /// assert_eq!(list, Some(hlist![0, 1]));
+    ///
+    /// let list = <HList![i32, i32, i32]>::take_from_iter([1, 2]);
+    /// assert_eq!(list, None);
+    ///
+    /// let list = <HList![]>::take_from_iter(core::iter::repeat(42));
+    /// assert_eq!(list, Some(hlist![]));
+    /// ```
+    ///
+    /// Elements which were not taken are left in the iterator:
+    ///
+    /// ```
+    /// use hlist2::{hlist, iter::TakeFromIter, HList};
+    ///
+    /// let mut iter = 1..=5;
+    /// let list = <HList![i32, i32]>::take_from_iter(&mut iter);
+    /// assert_eq!(list, Some(hlist![1, 2]));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [3, 4, 5]);
+    /// ```
+    fn take_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = A>;
+}
+
+impl<A> TakeFromIter<A> for Nil {
+    fn take_from_iter<I>(_: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Some(Nil)
+    }
+}
+
+impl<Head, Tail> TakeFromIter<Head> for Cons<Head, Tail>
+where
+    Tail: TakeFromIter<Head>,
+{
+    fn take_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Head>,
+    {
+        let mut iter = iter.into_iter();
+        let head = iter.next()?;
+        let tail = Tail::take_from_iter(iter)?;
+        Some(Cons(head, tail))
+    }
+}
+
 mod impl_details {
     use crate::{Cons, HList, Nil};
 
@@ -62,17 +62,27 @@
 //! safety guarantees the Rust programming language provides.
 //!
 //! This crate is `no_std`, so it can be used freely and with no fear in embedded environment.
+//!
+//! Operations which require memory allocation (for example, collecting elements into a vector)
+//! are available only with `alloc` feature enabled.
+//!
+//! Conversion into fixed-capacity collections of [`heapless`](https://docs.rs/heapless) crate,
+//! which do not require an allocator, is available only with `heapless` feature enabled.
 
 #![warn(clippy::all)]
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub use self::{cons::Cons, nil::Nil};
 
 pub mod convert;
 pub mod iter;
 pub mod ops;
+pub mod tuple;
 
 mod cons;
 mod nil;
@@ -163,6 +173,29 @@ where
     const LEN: usize = 1 + Tail::LEN;
 }
 
+/// Constructs a new heterogenous list with exactly one element.
+///
+/// This is the same as `hlist![value]`, but can be used where a macro is awkward,
+/// for example, in method chains or as a function pointer.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{hlist, Cons, Nil};
+///
+/// let list: Cons<i32, Nil> = hlist2::single(42);
+/// assert_eq!(list, hlist![42]);
+///
+/// let lists: Vec<_> = [1, 2].into_iter().map(hlist2::single).collect();
+/// assert_eq!(lists, [hlist![1], hlist![2]]);
+///
+/// const LIST: Cons<i32, Nil> = hlist2::single(42);
+/// assert_eq!(LIST, hlist![42]);
+/// ```
+pub const fn single<T>(value: T) -> Cons<T, Nil> {
+    Cons::single(value)
+}
+
 mod sealed {
     pub trait Sealed {}
 
@@ -0,0 +1,76 @@
+use crate::{Cons, HList, Nil};
+
+/// Find positions of extreme elements of the homogenous list.
+pub trait ArgExtreme: HList {
+    /// Returns the index of the maximum element of the homogenous list.
+    ///
+    /// If several elements are equally maximum, the index of the first one is returned.
+    /// Returns [`None`] if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ArgExtreme};
+    ///
+    /// assert_eq!(hlist![3, 7, 2, 7].argmax(), Some(1));
+    /// assert_eq!(hlist![42].argmax(), Some(0));
+    /// assert_eq!(hlist![].argmax(), None);
+    /// ```
+    fn argmax(&self) -> Option<usize>;
+
+    /// Returns the index of the minimum element of the homogenous list.
+    ///
+    /// If several elements are equally minimum, the index of the first one is returned.
+    /// Returns [`None`] if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ArgExtreme};
+    ///
+    /// assert_eq!(hlist![3, 2, 7, 2].argmin(), Some(1));
+    /// assert_eq!(hlist![42].argmin(), Some(0));
+    /// assert_eq!(hlist![].argmin(), None);
+    /// ```
+    fn argmin(&self) -> Option<usize>;
+}
+
+impl ArgExtreme for Nil {
+    fn argmax(&self) -> Option<usize> {
+        None
+    }
+
+    fn argmin(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<Head, Tail> ArgExtreme for Cons<Head, Tail>
+where
+    Head: Ord,
+    Tail: HList,
+    for<'a> &'a Self: IntoIterator<Item = &'a Head>,
+{
+    fn argmax(&self) -> Option<usize> {
+        arg_extreme(self, |item, best| item > best)
+    }
+
+    fn argmin(&self) -> Option<usize> {
+        arg_extreme(self, |item, best| item < best)
+    }
+}
+
+fn arg_extreme<'a, T, I>(list: I, is_better: fn(&T, &T) -> bool) -> Option<usize>
+where
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let best = list
+        .into_iter()
+        .enumerate()
+        .fold(None, |best, (index, item)| match best {
+            Some((_, best_item)) if !is_better(item, best_item) => best,
+            _ => Some((index, item)),
+        });
+    best.map(|(index, _)| index)
+}
@@ -0,0 +1,58 @@
+use crate::{Cons, HList, Nil};
+
+/// Convert the homogenous list of arrays into homogenous list of slices.
+pub trait AsSlices<T>: HList {
+    /// Type of new homogenous list of slices.
+    type Output<'a>: HList
+    where
+        Self: 'a;
+
+    /// Converts each array element of the homogenous list into a slice reference.
+    ///
+    /// Arrays of the list can have different lengths,
+    /// but resulting list is homogenous anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::AsSlices};
+    ///
+    /// let list = hlist![[1, 2], [3, 4, 5]];
+    /// let slices = list.as_slices();
+    /// assert_eq!(slices, hlist![&[1, 2][..], &[3, 4, 5][..]]);
+    ///
+    /// let hlist![first, second] = slices;
+    /// assert_eq!(first.len(), 2);
+    /// assert_eq!(second.len(), 3);
+    /// assert!(core::ptr::eq(first, &list.0[..]));
+    /// assert!(core::ptr::eq(second, &list.1 .0[..]));
+    /// ```
+    fn as_slices(&self) -> Self::Output<'_>;
+}
+
+impl<T> AsSlices<T> for Nil {
+    type Output<'a>
+        = Nil
+    where
+        Self: 'a;
+
+    fn as_slices(&self) -> Self::Output<'_> {
+        *self
+    }
+}
+
+impl<T, const M: usize, Tail> AsSlices<T> for Cons<[T; M], Tail>
+where
+    Tail: AsSlices<T>,
+{
+    type Output<'a>
+        = Cons<&'a [T], Tail::Output<'a>>
+    where
+        Self: 'a;
+
+    fn as_slices(&self) -> Self::Output<'_> {
+        let Cons(head, tail) = self;
+        let tail = tail.as_slices();
+        Cons(head, tail)
+    }
+}
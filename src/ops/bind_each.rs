@@ -0,0 +1,77 @@
+use alloc::boxed::Box;
+
+use crate::{Cons, HList, Nil};
+
+/// Bind each element of the homogenous list to a function,
+/// producing heterogenous list of [boxed](Box) closures.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait BindEach<T>: HList {
+    /// Type of heterogenous list of boxed closures, each of them returning `R`.
+    type Output<R>: HList;
+
+    /// Binds each element of the homogenous list to a function,
+    /// producing heterogenous list of [boxed](Box) closures of the same length.
+    ///
+    /// Each closure captures its element and calls the function with a clone of it.
+    /// Each closure has the same type, so it can be stored in the list of the same shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::BindEach};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let hlist![a, b, c] = list.bind_each_boxed(|x| x * 10);
+    /// let (mut a, mut b, mut c) = (a, b, c);
+    /// assert_eq!(a(), 10);
+    /// assert_eq!(b(), 20);
+    /// assert_eq!(c(), 30);
+    /// assert_eq!(a(), 10);
+    /// ```
+    ///
+    /// Bound closures can be invoked by iterating over the list:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::BindEach};
+    ///
+    /// let list = hlist!["hello", "world"];
+    /// let mut bound = list.bind_each_boxed(|s: &str| s.len());
+    /// let lengths: Vec<_> = (&mut bound).into_iter().map(|f| f()).collect();
+    /// assert_eq!(lengths, [5, 5]);
+    /// ```
+    fn bind_each_boxed<F, R>(self, f: F) -> Self::Output<R>
+    where
+        F: Fn(T) -> R + Clone + 'static,
+        T: Clone + 'static;
+}
+
+impl<T> BindEach<T> for Nil {
+    type Output<R> = Nil;
+
+    fn bind_each_boxed<F, R>(self, _: F) -> Self::Output<R>
+    where
+        F: Fn(T) -> R + Clone + 'static,
+        T: Clone + 'static,
+    {
+        self
+    }
+}
+
+impl<T, Tail> BindEach<T> for Cons<T, Tail>
+where
+    Tail: BindEach<T>,
+{
+    type Output<R> = Cons<Box<dyn FnMut() -> R>, Tail::Output<R>>;
+
+    fn bind_each_boxed<F, R>(self, f: F) -> Self::Output<R>
+    where
+        F: Fn(T) -> R + Clone + 'static,
+        T: Clone + 'static,
+    {
+        let Cons(head, tail) = self;
+        let tail = tail.bind_each_boxed(f.clone());
+        let head: Box<dyn FnMut() -> R> = Box::new(move || f(head.clone()));
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,93 @@
+use core::ops::{BitAnd, BitOr};
+
+use crate::{Cons, HList};
+
+use super::{zip_each::ZipEach, Fold, FoldFn, Folder};
+
+/// Combine heterogenous list of boolean rows element-wise.
+///
+/// Each element of the heterogenous list is a row, or homogenous list of booleans,
+/// and all the rows must have the same length.
+///
+/// The list of rows must not be empty: the first row is used as the initial value
+/// of element-wise combination, because the type (and therefore the length)
+/// of the identity row cannot be known for an empty list.
+///
+/// ```compile_fail
+/// use hlist2::{hlist, ops::BoolReduce};
+///
+/// let rows = hlist![];
+/// let row = rows.and_all();
+/// ```
+pub trait BoolReduce: HList {
+    /// Type of the resulting row of booleans.
+    type Output: HList;
+
+    /// Combines rows element-wise with logical AND.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::BoolReduce};
+    ///
+    /// let rows = hlist![hlist![true, false], hlist![true, true]];
+    /// assert_eq!(rows.and_all(), hlist![true, false]);
+    ///
+    /// let rows = hlist![
+    ///     hlist![true, true, false],
+    ///     hlist![true, false, true],
+    ///     hlist![true, true, true],
+    /// ];
+    /// assert_eq!(rows.and_all(), hlist![true, false, false]);
+    /// ```
+    fn and_all(self) -> Self::Output;
+
+    /// Combines rows element-wise with logical OR.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::BoolReduce};
+    ///
+    /// let rows = hlist![hlist![true, false], hlist![false, false]];
+    /// assert_eq!(rows.or_all(), hlist![true, false]);
+    ///
+    /// let rows = hlist![
+    ///     hlist![false, false, false],
+    ///     hlist![true, false, false],
+    ///     hlist![false, false, true],
+    /// ];
+    /// assert_eq!(rows.or_all(), hlist![true, false, true]);
+    /// ```
+    fn or_all(self) -> Self::Output;
+}
+
+impl<Row, Rows> BoolReduce for Cons<Row, Rows>
+where
+    Row: ZipEach<bool>,
+    Rows: Fold<Row, Folder<ZipWith>>,
+{
+    type Output = Row;
+
+    fn and_all(self) -> Self::Output {
+        let Cons(first, rows) = self;
+        rows.fold(first, Folder(ZipWith(bool::bitand)))
+    }
+
+    fn or_all(self) -> Self::Output {
+        let Cons(first, rows) = self;
+        rows.fold(first, Folder(ZipWith(bool::bitor)))
+    }
+}
+
+pub struct ZipWith(fn(bool, bool) -> bool);
+
+impl<Row> FoldFn<Row, Row> for ZipWith
+where
+    Row: ZipEach<bool>,
+{
+    fn fold(&mut self, accumulator: Row, item: Row) -> Row {
+        let Self(op) = *self;
+        accumulator.zip_with(item, op)
+    }
+}
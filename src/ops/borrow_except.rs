@@ -0,0 +1,60 @@
+use super::{Index, Remove, ToRef};
+
+/// Borrow all elements of the heterogenous list except an element of some type.
+pub trait BorrowExcept: ToRef {
+    /// Retrieves a heterogenous list of mutable references to all the elements
+    /// of the heterogenous list except an element of provided type, which is left alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::BorrowExcept};
+    ///
+    /// let mut list = hlist![1_i32, 2.0_f64, true];
+    ///
+    /// let hlist![a, b] = list.borrow_except::<f64, _>();
+    /// *a += 10;
+    /// *b = false;
+    ///
+    /// assert_eq!(list, hlist![11, 2.0, false]);
+    /// ```
+    ///
+    /// Excluded element can still be used after the borrow ends:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{BorrowExcept, Get}};
+    ///
+    /// let mut list = hlist![1_i32, 2.0_f64, true];
+    ///
+    /// let hlist![a, b] = list.borrow_except::<f64, _>();
+    /// *a *= 2;
+    /// *b = !*b;
+    ///
+    /// let excluded: &f64 = list.get();
+    /// assert_eq!(*excluded, 2.0);
+    /// ```
+    fn borrow_except<'a, T, I>(
+        &'a mut self,
+    ) -> <Self::RefMut<'a> as Remove<&'a mut T, I>>::Remainder
+    where
+        Self::RefMut<'a>: Remove<&'a mut T, I>,
+        T: 'a,
+        I: Index;
+}
+
+impl<L> BorrowExcept for L
+where
+    L: ToRef,
+{
+    fn borrow_except<'a, T, I>(
+        &'a mut self,
+    ) -> <Self::RefMut<'a> as Remove<&'a mut T, I>>::Remainder
+    where
+        Self::RefMut<'a>: Remove<&'a mut T, I>,
+        T: 'a,
+        I: Index,
+    {
+        let (_, remainder) = self.to_mut().remove();
+        remainder
+    }
+}
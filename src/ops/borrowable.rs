@@ -0,0 +1,66 @@
+use crate::{Cons, Nil};
+
+use super::ToRef;
+
+/// Convert the heterogenous list into heterogenous list of references and back.
+///
+/// This trait unifies the owned heterogenous list with its borrowed form,
+/// which is the [`Ref`](ToRef::Ref) type of [`ToRef`] trait,
+/// so generic code can accept either of them and normalize into the owned one.
+pub trait Borrowable: ToRef {
+    /// Converts the heterogenous list into heterogenous list of references.
+    ///
+    /// This is the same as [`ToRef::to_ref()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Borrowable};
+    ///
+    /// let list = hlist![1, 2.0, false];
+    /// assert_eq!(list.as_borrowed(), hlist![&1, &2.0, &false]);
+    /// ```
+    fn as_borrowed(&self) -> Self::Ref<'_>;
+
+    /// Creates the owned heterogenous list from heterogenous list of references
+    /// by cloning each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Borrowable, HList};
+    ///
+    /// let list = hlist![1, String::from("hello"), false];
+    /// let borrowed = list.as_borrowed();
+    /// let owned = <HList![i32, String, bool]>::into_owned(borrowed);
+    /// assert_eq!(owned, list);
+    /// ```
+    fn into_owned(borrowed: Self::Ref<'_>) -> Self;
+}
+
+impl Borrowable for Nil {
+    fn as_borrowed(&self) -> Self::Ref<'_> {
+        self.to_ref()
+    }
+
+    fn into_owned(borrowed: Self::Ref<'_>) -> Self {
+        borrowed
+    }
+}
+
+impl<Head, Tail> Borrowable for Cons<Head, Tail>
+where
+    Head: Clone,
+    Tail: Borrowable,
+{
+    fn as_borrowed(&self) -> Self::Ref<'_> {
+        self.to_ref()
+    }
+
+    fn into_owned(borrowed: Self::Ref<'_>) -> Self {
+        let Cons(head, tail) = borrowed;
+        let head = head.clone();
+        let tail = Tail::into_owned(tail);
+        Cons(head, tail)
+    }
+}
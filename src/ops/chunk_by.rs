@@ -0,0 +1,73 @@
+use crate::{Cons, HList, Nil};
+
+use super::{Index, SplitAt};
+
+/// Split the heterogenous list into chunks of provided sizes.
+///
+/// Sizes of chunks are provided as a heterogenous list of type-level [indices](Index),
+/// where each index is the count of elements in the corresponding chunk.
+pub trait ChunkBy<Sizes>: HList
+where
+    Sizes: HList,
+{
+    /// Type of heterogenous list of chunks.
+    type Output: HList;
+
+    /// Splits the heterogenous list into chunks of provided sizes.
+    ///
+    /// Sizes of chunks must sum exactly to the length of the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{ChunkBy, Here, There}, HList};
+    ///
+    /// type One = There<Here>;
+    /// type Two = There<One>;
+    ///
+    /// let list = hlist![1, 2, 3, 4, 5];
+    /// let chunks = ChunkBy::<HList![Two, One, Two]>::chunk_by(list);
+    /// assert_eq!(chunks, hlist![hlist![1, 2], hlist![3], hlist![4, 5]]);
+    ///
+    /// let list = hlist![1, 2.0, true];
+    /// let chunks = ChunkBy::<HList![Here, One, Two]>::chunk_by(list);
+    /// assert_eq!(chunks, hlist![hlist![], hlist![1], hlist![2.0, true]]);
+    /// ```
+    ///
+    /// Sizes of chunks must sum exactly to the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{ChunkBy, Here, There}, HList};
+    ///
+    /// type One = There<Here>;
+    /// type Two = There<One>;
+    ///
+    /// let list = hlist![1, 2, 3, 4, 5];
+    /// let chunks = ChunkBy::<HList![Two, Two]>::chunk_by(list);
+    /// ```
+    fn chunk_by(self) -> Self::Output;
+}
+
+impl ChunkBy<Nil> for Nil {
+    type Output = Nil;
+
+    fn chunk_by(self) -> Self::Output {
+        self
+    }
+}
+
+impl<T, Size, Sizes> ChunkBy<Cons<Size, Sizes>> for T
+where
+    T: SplitAt<Size>,
+    T::Suffix: ChunkBy<Sizes>,
+    Size: Index,
+    Sizes: HList,
+{
+    type Output = Cons<T::Prefix, <T::Suffix as ChunkBy<Sizes>>::Output>;
+
+    fn chunk_by(self) -> Self::Output {
+        let (prefix, suffix) = self.split_at();
+        let tail = suffix.chunk_by();
+        Cons(prefix, tail)
+    }
+}
@@ -0,0 +1,96 @@
+use alloc::vec::Vec;
+
+use crate::HList;
+
+use super::{Fold, FoldFn, Folder, MapUniform};
+
+/// Collect elements of the heterogenous list into a [vector](Vec) of the common type.
+///
+/// Common type of elements is a generic parameter of the trait, not of the method,
+/// so it cannot be provided with turbofish syntax on the method call.
+/// Instead, it is inferred from the mapper or from the type annotation of the result,
+/// or it can be provided with fully qualified syntax:
+///
+/// ```
+/// use hlist2::{hlist, ops::CollectInto};
+///
+/// let list = hlist![1, 2.5];
+/// let mapper = hlist![|i: i32| i.into(), |f: f64| f];
+/// let values = CollectInto::<f64, _>::collect_into(list, mapper);
+/// assert_eq!(values, [1.0, 2.5]);
+/// ```
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait CollectInto<T, Mapper>: HList {
+    /// Collects elements of the heterogenous list into a [vector](Vec) of the common type
+    /// by transforming each element with mapper first.
+    ///
+    /// Order of elements in the vector is the same as in the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{
+    ///     hlist,
+    ///     ops::{CollectInto, MapFn, Mapper},
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Value {
+    ///     Int(i32),
+    ///     Str(&'static str),
+    /// }
+    ///
+    /// struct IntoValue;
+    ///
+    /// impl MapFn<i32> for IntoValue {
+    ///     type Output = Value;
+    ///     fn map(&mut self, i: i32) -> Value { Value::Int(i) }
+    /// }
+    /// impl MapFn<&'static str> for IntoValue {
+    ///     type Output = Value;
+    ///     fn map(&mut self, s: &'static str) -> Value { Value::Str(s) }
+    /// }
+    ///
+    /// let list = hlist![1, "s", 2];
+    /// let values = list.collect_into(Mapper(IntoValue));
+    /// assert_eq!(values, [Value::Int(1), Value::Str("s"), Value::Int(2)]);
+    /// ```
+    ///
+    /// Heterogenous list of closures can be used as mapper too:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::CollectInto};
+    ///
+    /// let list = hlist![1, 2.5, "3"];
+    /// let values = list.collect_into(
+    ///     hlist![
+    ///         |i: i32| i as f64,
+    ///         |f: f64| f,
+    ///         |s: &str| s.parse().unwrap(),
+    ///     ]
+    /// );
+    /// assert_eq!(values, [1.0, 2.5, 3.0]);
+    /// ```
+    fn collect_into(self, mapper: Mapper) -> Vec<T>;
+}
+
+impl<L, T, M> CollectInto<T, M> for L
+where
+    L: MapUniform<T, M>,
+    L::Output: Fold<Vec<T>, Folder<Push>>,
+{
+    fn collect_into(self, mapper: M) -> Vec<T> {
+        let list = self.map_uniform(mapper);
+        list.fold(Vec::new(), Folder(Push))
+    }
+}
+
+pub struct Push;
+
+impl<T> FoldFn<Vec<T>, T> for Push {
+    fn fold(&mut self, mut accumulator: Vec<T>, item: T) -> Vec<T> {
+        accumulator.push(item);
+        accumulator
+    }
+}
@@ -0,0 +1,132 @@
+use core::cmp::Ordering;
+
+use crate::{Cons, HList, Nil};
+
+/// Type with an associative binary operation.
+///
+/// Implementations must ensure that the operation is associative, or
+/// `a.combine(b).combine(c)` is equal to `a.combine(b.combine(c))`.
+pub trait Semigroup {
+    /// Combines two values into one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use hlist2::ops::Semigroup;
+    ///
+    /// assert_eq!(Ordering::Equal.combine(Ordering::Less), Ordering::Less);
+    /// assert_eq!(Some(Ordering::Greater).combine(None), Some(Ordering::Greater));
+    /// ```
+    fn combine(self, other: Self) -> Self;
+}
+
+impl Semigroup for () {
+    fn combine(self, _: Self) -> Self {}
+}
+
+/// Values are combined lexicographically: the first value which is not equal wins.
+impl Semigroup for Ordering {
+    fn combine(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+/// [`None`] is the identity: values inside of [`Some`] are combined together.
+impl<T> Semigroup for Option<T>
+where
+    T: Semigroup,
+{
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(this), Some(other)) => Some(this.combine(other)),
+            (this, None) => this,
+            (None, other) => other,
+        }
+    }
+}
+
+/// Strings are concatenated.
+#[cfg(feature = "alloc")]
+impl Semigroup for alloc::string::String {
+    fn combine(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+/// Vectors are concatenated.
+#[cfg(feature = "alloc")]
+impl<T> Semigroup for alloc::vec::Vec<T> {
+    fn combine(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
+/// Combine all elements of the homogenous list into a single value.
+pub trait Combine<T>: HList
+where
+    T: Semigroup,
+{
+    /// Combines all elements of the homogenous list into a single value
+    /// with an associative operation of the [semigroup](Semigroup).
+    ///
+    /// This generalizes [`Sum`](core::iter::Sum) and [`Product`](core::iter::Product)
+    /// to any associative operation. Returns [`None`] if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use hlist2::{hlist, ops::Combine};
+    ///
+    /// let list = hlist![Ordering::Equal, Ordering::Less, Ordering::Greater];
+    /// assert_eq!(list.combine_all(), Some(Ordering::Less));
+    ///
+    /// let list = hlist![];
+    /// assert_eq!(Combine::<Ordering>::combine_all(list), None);
+    /// ```
+    ///
+    /// With `alloc` feature enabled, strings and vectors can be combined by concatenation:
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use hlist2::{hlist, ops::Combine};
+    ///
+    /// let list = hlist!["a".to_string(), "b".to_string()];
+    /// assert_eq!(list.combine_all(), Some("ab".to_string()));
+    ///
+    /// let list = hlist![vec![1], vec![2, 3]];
+    /// assert_eq!(list.combine_all(), Some(vec![1, 2, 3]));
+    /// # }
+    /// ```
+    fn combine_all(self) -> Option<T>;
+}
+
+impl<T> Combine<T> for Nil
+where
+    T: Semigroup,
+{
+    fn combine_all(self) -> Option<T> {
+        None
+    }
+}
+
+impl<T, Tail> Combine<T> for Cons<T, Tail>
+where
+    T: Semigroup,
+    Tail: Combine<T>,
+{
+    fn combine_all(self) -> Option<T> {
+        let Cons(head, tail) = self;
+        let combined = match tail.combine_all() {
+            Some(tail) => head.combine(tail),
+            None => head,
+        };
+        Some(combined)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Cons, HList, Nil};
+
+/// Compose heterogenous list of functions into a single function.
+pub trait Compose<Input>: HList {
+    /// Type of the output of composed function.
+    type Output;
+
+    /// Composes heterogenous list of functions into a single function.
+    ///
+    /// Functions are applied from left to right: the first function of the list
+    /// is applied to the input, then the second one is applied to the output of the first one,
+    /// and so on. Composition of an empty list is an identity function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Compose};
+    ///
+    /// let composed = hlist![|x: i32| x + 1, |x: i32| x * 2].compose();
+    /// assert_eq!(composed(3), (3 + 1) * 2);
+    ///
+    /// let composed = hlist![|x: i32| x * 2, |x: i32| x + 1].compose();
+    /// assert_eq!(composed(3), 3 * 2 + 1);
+    /// ```
+    ///
+    /// Output of each function can differ from its input:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Compose};
+    ///
+    /// let composed = hlist![
+    ///     |s: &str| s.len(),
+    ///     |len: usize| len % 2 == 0,
+    ///     |even: bool| if even { "even" } else { "odd" },
+    /// ]
+    /// .compose();
+    /// assert_eq!(composed("hello"), "odd");
+    /// ```
+    ///
+    /// Composition of an empty list returns its input:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Compose};
+    ///
+    /// let identity = hlist![].compose();
+    /// assert_eq!(identity(42), 42);
+    /// ```
+    fn compose(self) -> impl FnOnce(Input) -> Self::Output;
+}
+
+impl<Input> Compose<Input> for Nil {
+    type Output = Input;
+
+    fn compose(self) -> impl FnOnce(Input) -> Self::Output {
+        |input| input
+    }
+}
+
+impl<Input, R, Head, Tail> Compose<Input> for Cons<Head, Tail>
+where
+    Head: FnOnce(Input) -> R,
+    Tail: Compose<R>,
+{
+    type Output = Tail::Output;
+
+    fn compose(self) -> impl FnOnce(Input) -> Self::Output {
+        let Cons(head, tail) = self;
+        let tail = tail.compose();
+        move |input| tail(head(input))
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Cons, HList, Nil};
+
+/// Callback which can accept a value of any type.
+///
+/// Unlike closures, which accept values of specific types only,
+/// this trait allows a single object to be called with each element of the heterogenous list.
+pub trait HListCallback {
+    /// Accepts a value of any type.
+    fn call<T>(&mut self, value: T);
+}
+
+/// Move each element of the heterogenous list into a [generic callback](HListCallback).
+pub trait ConsumeEach: HList {
+    /// Moves each element of the heterogenous list into the callback, returning the callback then.
+    ///
+    /// Elements are moved from the beginning of the heterogenous list to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::any::type_name;
+    ///
+    /// use hlist2::{hlist, ops::{ConsumeEach, HListCallback}};
+    ///
+    /// #[derive(Default)]
+    /// struct TypeNames(Vec<&'static str>);
+    ///
+    /// impl HListCallback for TypeNames {
+    ///     fn call<T>(&mut self, _: T) {
+    ///         let Self(names) = self;
+    ///         names.push(type_name::<T>());
+    ///     }
+    /// }
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    /// let TypeNames(names) = list.consume_each(TypeNames::default());
+    /// assert_eq!(names, ["i32", "f64", "bool"]);
+    /// ```
+    fn consume_each<C>(self, callback: C) -> C
+    where
+        C: HListCallback;
+}
+
+impl ConsumeEach for Nil {
+    fn consume_each<C>(self, callback: C) -> C
+    where
+        C: HListCallback,
+    {
+        callback
+    }
+}
+
+impl<Head, Tail> ConsumeEach for Cons<Head, Tail>
+where
+    Tail: ConsumeEach,
+{
+    fn consume_each<C>(self, mut callback: C) -> C
+    where
+        C: HListCallback,
+    {
+        let Cons(head, tail) = self;
+        callback.call(head);
+        tail.consume_each(callback)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Cons, HList, Nil};
+
+use super::{Here, Index, ManyIndex, There};
+
+/// Heterogenous list which contains types of other heterogenous list in the same order.
+///
+/// Types of the subsequence should appear in the list in order,
+/// but not necessarily contiguously.
+///
+/// Generic parameter `I` is the position of each type of the subsequence
+/// relative to the previous matched type. It is inferred by the compiler
+/// in the same way as for [`Get`](super::Get) trait,
+/// so matched types should not be ambiguous.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{ops::{ContainsSubsequence, ManyIndex}, HList};
+///
+/// fn contains<L, Sub, I>()
+/// where
+///     L: ContainsSubsequence<Sub, I>,
+///     Sub: HList,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// contains::<HList![i32, f64, bool, &str], HList![i32, bool], _>();
+/// contains::<HList![i32, f64, bool, &str], HList![f64, &str], _>();
+/// contains::<HList![i32, f64, bool, &str], HList![], _>();
+/// ```
+///
+/// Types of the subsequence must appear in the list in the same order:
+///
+/// ```compile_fail
+/// use hlist2::{ops::{ContainsSubsequence, ManyIndex}, HList};
+///
+/// fn contains<L, Sub, I>()
+/// where
+///     L: ContainsSubsequence<Sub, I>,
+///     Sub: HList,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// contains::<HList![i32, f64, bool, &str], HList![bool, i32], _>();
+/// ```
+pub trait ContainsSubsequence<Sub, I>: HList
+where
+    Sub: HList,
+    I: ManyIndex,
+{
+}
+
+impl<T> ContainsSubsequence<Nil, Nil> for T where T: HList {}
+
+impl<Head, Tail, SubTail, I> ContainsSubsequence<Cons<Head, SubTail>, Cons<Here, I>>
+    for Cons<Head, Tail>
+where
+    Tail: ContainsSubsequence<SubTail, I>,
+    SubTail: HList,
+    I: ManyIndex,
+{
+}
+
+impl<Head, Tail, SubHead, SubTail, Next, I>
+    ContainsSubsequence<Cons<SubHead, SubTail>, Cons<There<Next>, I>> for Cons<Head, Tail>
+where
+    Tail: ContainsSubsequence<Cons<SubHead, SubTail>, Cons<Next, I>>,
+    SubTail: HList,
+    Next: Index,
+    I: ManyIndex,
+{
+}
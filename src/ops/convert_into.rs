@@ -0,0 +1,60 @@
+use crate::{Cons, HList, Nil};
+
+/// Convert each element of the heterogenous list into the element of another heterogenous list.
+///
+/// This is an element-wise [`Into`] conversion across the heterogenous list.
+///
+/// Target heterogenous list is a generic parameter of the trait, not of the method,
+/// so it cannot be provided with turbofish syntax on the method call.
+/// Instead, it is inferred from the type annotation of the result,
+/// or it can be provided with fully qualified syntax:
+///
+/// ```
+/// use hlist2::{hlist, HList, ops::ConvertInto};
+///
+/// let list = hlist![1_u8, 2_u16];
+/// let converted = ConvertInto::<HList![u32, u32]>::convert_into(list);
+/// assert_eq!(converted, hlist![1_u32, 2_u32]);
+/// ```
+pub trait ConvertInto<Target>: HList
+where
+    Target: HList,
+{
+    /// Converts each element of the heterogenous list
+    /// into the corresponding element of the target heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::ConvertInto};
+    ///
+    /// let list = hlist![1_u8, 2_u16];
+    /// let converted: HList![u32, u32] = list.convert_into();
+    /// assert_eq!(converted, hlist![1_u32, 2_u32]);
+    ///
+    /// let list = hlist!["hello world", 'a'];
+    /// let converted: HList![String, u32] = list.convert_into();
+    /// assert_eq!(converted, hlist![String::from("hello world"), 97]);
+    /// ```
+    fn convert_into(self) -> Target;
+}
+
+impl ConvertInto<Nil> for Nil {
+    fn convert_into(self) -> Nil {
+        self
+    }
+}
+
+impl<Head, Tail, THead, TTail> ConvertInto<Cons<THead, TTail>> for Cons<Head, Tail>
+where
+    Head: Into<THead>,
+    Tail: ConvertInto<TTail>,
+    TTail: HList,
+{
+    fn convert_into(self) -> Cons<THead, TTail> {
+        let Cons(head, tail) = self;
+        let head = head.into();
+        let tail = tail.convert_into();
+        Cons(head, tail)
+    }
+}
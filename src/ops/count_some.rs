@@ -0,0 +1,93 @@
+use crate::{Cons, HList, Nil};
+
+/// Inspect heterogenous list which consists of [`Option`]s only.
+pub trait CountSome: HList {
+    /// Counts elements of the heterogenous list which are [`Some`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::CountSome};
+    ///
+    /// let list = hlist![Some(1), None::<f64>, Some(true)];
+    /// assert_eq!(list.count_some(), 2);
+    ///
+    /// let list = hlist![None::<i32>, None::<f64>];
+    /// assert_eq!(list.count_some(), 0);
+    /// ```
+    fn count_some(&self) -> usize;
+
+    /// Checks if all elements of the heterogenous list are [`Some`].
+    ///
+    /// Returns `true` for an empty heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::CountSome};
+    ///
+    /// let list = hlist![Some(1), Some(2.0), Some(true)];
+    /// assert!(list.all_some());
+    ///
+    /// let list = hlist![Some(1), None::<f64>, Some(true)];
+    /// assert!(!list.all_some());
+    ///
+    /// assert!(hlist![].all_some());
+    /// ```
+    fn all_some(&self) -> bool;
+
+    /// Checks if any element of the heterogenous list is [`Some`].
+    ///
+    /// Returns `false` for an empty heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::CountSome};
+    ///
+    /// let list = hlist![None::<i32>, None::<f64>, Some(true)];
+    /// assert!(list.any_some());
+    ///
+    /// let list = hlist![None::<i32>, None::<f64>];
+    /// assert!(!list.any_some());
+    ///
+    /// assert!(!hlist![].any_some());
+    /// ```
+    fn any_some(&self) -> bool;
+}
+
+impl CountSome for Nil {
+    fn count_some(&self) -> usize {
+        0
+    }
+
+    fn all_some(&self) -> bool {
+        true
+    }
+
+    fn any_some(&self) -> bool {
+        false
+    }
+}
+
+impl<Head, Tail> CountSome for Cons<Option<Head>, Tail>
+where
+    Tail: CountSome,
+{
+    fn count_some(&self) -> usize {
+        let Cons(head, tail) = self;
+        let head = head.is_some() as usize;
+        let tail = tail.count_some();
+        head + tail
+    }
+
+    fn all_some(&self) -> bool {
+        let Cons(head, tail) = self;
+        head.is_some() && tail.all_some()
+    }
+
+    fn any_some(&self) -> bool {
+        let Cons(head, tail) = self;
+        head.is_some() || tail.any_some()
+    }
+}
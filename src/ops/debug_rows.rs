@@ -0,0 +1,43 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate::Len;
+
+use super::ForEachDebug;
+
+/// Format each element of the heterogenous list as a row of a table.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait ToDebugRows: ForEachDebug + Len {
+    /// Formats each element of the heterogenous list with [`Debug`](core::fmt::Debug) trait
+    /// and pairs the resulting string with the index of the element.
+    ///
+    /// Rows are in the order of elements, from the beginning of the heterogenous list to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ToDebugRows};
+    ///
+    /// let list = hlist![1_i32, true, "three"];
+    /// let rows = list.to_debug_rows();
+    /// assert_eq!(
+    ///     rows,
+    ///     [(0, "1".to_string()), (1, "true".to_string()), (2, "\"three\"".to_string())],
+    /// );
+    ///
+    /// let rows = hlist![].to_debug_rows();
+    /// assert!(rows.is_empty());
+    /// ```
+    fn to_debug_rows(&self) -> Vec<(usize, String)>;
+}
+
+impl<T> ToDebugRows for T
+where
+    T: ForEachDebug + Len,
+{
+    fn to_debug_rows(&self) -> Vec<(usize, String)> {
+        let mut rows = Vec::with_capacity(T::LEN);
+        self.for_each_debug(|item| rows.push((rows.len(), format!("{item:?}"))));
+        rows
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+
+use crate::{iter::Homogenous, Len};
+
+/// Remove consecutive repeated elements of the homogenous list.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait DedupConsecutive: Homogenous + Len
+where
+    Self::Item: PartialEq,
+{
+    /// Collects elements of the homogenous list into a [vector](Vec),
+    /// removing consecutive repeated elements.
+    ///
+    /// Resulting vector is returned because count of remaining elements
+    /// is known only at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::DedupConsecutive};
+    ///
+    /// let list = hlist![1, 1, 2, 2, 2, 3];
+    /// assert_eq!(list.dedup_consecutive(), [1, 2, 3]);
+    ///
+    /// let list = hlist![1, 2, 2, 3, 1];
+    /// assert_eq!(list.dedup_consecutive(), [1, 2, 3, 1]);
+    ///
+    /// let list = hlist![1, 2, 3, 3];
+    /// assert_eq!(list.dedup_consecutive(), [1, 2, 3]);
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// assert_eq!(list.dedup_consecutive(), [1, 2, 3]);
+    /// ```
+    fn dedup_consecutive(self) -> Vec<Self::Item>;
+}
+
+impl<T> DedupConsecutive for T
+where
+    T: Homogenous + Len,
+    T::Item: PartialEq,
+{
+    fn dedup_consecutive(self) -> Vec<Self::Item> {
+        let mut vec = Vec::with_capacity(T::LEN);
+        vec.extend(self);
+        vec.dedup();
+        vec
+    }
+}
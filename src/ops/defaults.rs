@@ -0,0 +1,42 @@
+use crate::{Cons, HList, Nil};
+
+/// Create heterogenous list with default values of each element type.
+pub trait Defaults: HList {
+    /// Creates a new heterogenous list where each element
+    /// is the [default](Default) value of its type.
+    ///
+    /// This is the same as [`Default::default()`], but can be used in generic code
+    /// bounded on heterogenous lists only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Defaults, HList};
+    ///
+    /// let list = <HList![i32, f64, bool]>::defaults();
+    /// assert_eq!(list, hlist![0, 0.0, false]);
+    /// assert_eq!(list, Default::default());
+    ///
+    /// let list: HList![String, Option<u8>] = Defaults::defaults();
+    /// assert_eq!(list, hlist![String::new(), None]);
+    /// ```
+    fn defaults() -> Self;
+}
+
+impl Defaults for Nil {
+    fn defaults() -> Self {
+        Nil
+    }
+}
+
+impl<Head, Tail> Defaults for Cons<Head, Tail>
+where
+    Head: Default,
+    Tail: Defaults,
+{
+    fn defaults() -> Self {
+        let head = Head::default();
+        let tail = Tail::defaults();
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::HList;
+
+use super::{Index, Position};
+
+/// Compute the distance between two elements of the heterogenous list by their types.
+pub trait DistanceBetween: HList {
+    /// Returns signed offset from the element of type `A` to the element of type `B`.
+    ///
+    /// Distance is negative if the element of type `B` precedes the element of type `A`,
+    /// and zero if both types refer to the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::DistanceBetween};
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    /// assert_eq!(list.distance_between::<i32, bool, _, _>(), 2);
+    /// assert_eq!(list.distance_between::<bool, f64, _, _>(), -1);
+    /// assert_eq!(list.distance_between::<f64, f64, _, _>(), 0);
+    /// ```
+    fn distance_between<A, B, IA, IB>(&self) -> isize
+    where
+        Self: Position<A, IA> + Position<B, IB>,
+        IA: Index,
+        IB: Index;
+}
+
+impl<L> DistanceBetween for L
+where
+    L: HList,
+{
+    fn distance_between<A, B, IA, IB>(&self) -> isize
+    where
+        Self: Position<A, IA> + Position<B, IB>,
+        IA: Index,
+        IB: Index,
+    {
+        let a = <Self as Position<A, IA>>::POSITION as isize;
+        let b = <Self as Position<B, IB>>::POSITION as isize;
+        b - a
+    }
+}
@@ -0,0 +1,38 @@
+use core::ops::{Add, Mul};
+
+use crate::HList;
+
+use super::ZipFold;
+
+/// Compute the dot product of two homogenous lists.
+pub trait Dot<T>: HList
+where
+    T: Mul<Output = T> + Add<Output = T> + Default,
+{
+    /// Computes the sum of element-wise products of two homogenous lists.
+    ///
+    /// Returns [default](Default) value (which is zero for numbers) if lists are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Dot};
+    ///
+    /// assert_eq!(hlist![1, 2, 3].dot(hlist![4, 5, 6]), 1 * 4 + 2 * 5 + 3 * 6);
+    /// assert_eq!(hlist![1.5, -2.0].dot(hlist![2.0, 0.25]), 1.5 * 2.0 + -2.0 * 0.25);
+    ///
+    /// let dot: i32 = hlist![].dot(hlist![]);
+    /// assert_eq!(dot, 0);
+    /// ```
+    fn dot(self, other: Self) -> T;
+}
+
+impl<T, L> Dot<T> for L
+where
+    T: Mul<Output = T> + Add<Output = T> + Default,
+    L: ZipFold<L, T, fn(T, T, T) -> T>,
+{
+    fn dot(self, other: Self) -> T {
+        self.zip_fold(other, T::default(), |acc, a, b| acc + a * b)
+    }
+}
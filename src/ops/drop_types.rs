@@ -0,0 +1,60 @@
+use crate::HList;
+
+use super::{ManyIndex, RemoveMany};
+
+/// Drop elements of provided types from the heterogenous list, keeping the rest of them.
+///
+/// This is the same as [`RemoveMany`] trait, but only the remainder is returned.
+///
+/// Index generic parameter is used to determine which elements should be dropped,
+/// because there may be more than one way if type of elements is not unique.
+/// In such case, index should be specified explicitly.
+pub trait DropTypes<T, I>: RemoveMany<T, I>
+where
+    T: HList,
+    I: ManyIndex,
+{
+    /// Drops elements of provided types from the heterogenous list, keeping the rest of them.
+    ///
+    /// Order of remaining elements is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::DropTypes};
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    ///
+    /// let remainder = DropTypes::<HList![], _>::drop_types(list);
+    /// assert_eq!(remainder, hlist![1, 2.0, true]);
+    ///
+    /// let remainder = DropTypes::<HList![i32], _>::drop_types(list);
+    /// assert_eq!(remainder, hlist![2.0, true]);
+    ///
+    /// let remainder = DropTypes::<HList![bool, i32, f64], _>::drop_types(list);
+    /// assert_eq!(remainder, hlist![]);
+    /// ```
+    ///
+    /// If type of elements is not unique, index should be specified explicitly:
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::{DropTypes, Here, There}};
+    ///
+    /// let list = hlist![1, 2.0, 3];
+    /// let remainder = DropTypes::<HList![i32], HList![There<There<Here>>]>::drop_types(list);
+    /// assert_eq!(remainder, hlist![1, 2.0]);
+    /// ```
+    fn drop_types(self) -> Self::Remainder;
+}
+
+impl<L, T, I> DropTypes<T, I> for L
+where
+    L: RemoveMany<T, I>,
+    T: HList,
+    I: ManyIndex,
+{
+    fn drop_types(self) -> Self::Remainder {
+        let (_, remainder) = self.remove_many();
+        remainder
+    }
+}
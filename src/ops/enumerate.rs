@@ -0,0 +1,45 @@
+use crate::{Cons, HList, Len, Nil};
+
+/// Pair each element of the heterogenous list with its distance from the end.
+pub trait EnumerateFromEnd: Len {
+    /// Type of new heterogenous list with indexed elements.
+    type Output: HList;
+
+    /// Pairs each element of the heterogenous list with its distance from the end.
+    ///
+    /// The last element gets index `0`, and the first one gets index `LEN - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::EnumerateFromEnd};
+    ///
+    /// let list = hlist!['a', "b", 3.0];
+    /// let list = list.enumerate_from_end();
+    /// assert_eq!(list, hlist![(2, 'a'), (1, "b"), (0, 3.0)]);
+    /// ```
+    fn enumerate_from_end(self) -> Self::Output;
+}
+
+impl EnumerateFromEnd for Nil {
+    type Output = Nil;
+
+    fn enumerate_from_end(self) -> Self::Output {
+        self
+    }
+}
+
+impl<Head, Tail> EnumerateFromEnd for Cons<Head, Tail>
+where
+    Tail: EnumerateFromEnd,
+{
+    type Output = Cons<(usize, Head), Tail::Output>;
+
+    fn enumerate_from_end(self) -> Self::Output {
+        // head is always at forward index 0 of the current sublist
+        let index = Self::LEN - 1;
+        let Cons(head, tail) = self;
+        let tail = tail.enumerate_from_end();
+        Cons((index, head), tail)
+    }
+}
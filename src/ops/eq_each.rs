@@ -0,0 +1,63 @@
+use crate::{Cons, HList, Nil};
+
+/// Compare elements of two heterogenous lists of the same length one by one.
+pub trait EqEach<Other>: HList
+where
+    Other: HList,
+{
+    /// Type of homogenous list of comparison results.
+    type Output: HList;
+
+    /// Compares corresponding elements of two heterogenous lists for equality,
+    /// returning homogenous list of comparison results.
+    ///
+    /// Both heterogenous lists must have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::EqEach};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// assert_eq!(list.eq_each(&hlist![1, 0, 3]), hlist![true, false, true]);
+    ///
+    /// let list = hlist![1, 2.0, "hello"];
+    /// assert_eq!(list.eq_each(&hlist![1, 2.5, "hello"]), hlist![true, false, true]);
+    /// ```
+    ///
+    /// Lists of different lengths cannot be compared:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::EqEach};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let result = list.eq_each(&hlist![1, 2]);
+    /// ```
+    fn eq_each(&self, other: &Other) -> Self::Output;
+}
+
+impl EqEach<Nil> for Nil {
+    type Output = Nil;
+
+    fn eq_each(&self, _: &Nil) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail, OHead, OTail> EqEach<Cons<OHead, OTail>> for Cons<Head, Tail>
+where
+    Head: PartialEq<OHead>,
+    Tail: EqEach<OTail>,
+    OTail: HList,
+{
+    type Output = Cons<bool, Tail::Output>;
+
+    fn eq_each(&self, other: &Cons<OHead, OTail>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let Cons(other_head, other_tail) = other;
+
+        let head = head == other_head;
+        let tail = tail.eq_each(other_tail);
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,51 @@
+use super::{Index, Remove, ToRef};
+
+/// Compare heterogenous lists for equality ignoring an element of some type.
+pub trait EqIgnoring: ToRef {
+    /// Compares two heterogenous lists for equality ignoring an element of provided type.
+    ///
+    /// This is useful when one of elements is non-deterministic (like a timestamp).
+    /// Element of provided type is removed from both lists, and the remainders are compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::EqIgnoring};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Timestamp(u64);
+    ///
+    /// let first = hlist![Timestamp(1), "hello world", 42];
+    /// let second = hlist![Timestamp(2), "hello world", 42];
+    /// assert_ne!(first, second);
+    /// assert!(first.eq_ignoring::<Timestamp, _>(&second));
+    ///
+    /// let first = hlist![1, 2.0, Timestamp(1)];
+    /// let second = hlist![1, 2.0, Timestamp(2)];
+    /// assert!(first.eq_ignoring::<Timestamp, _>(&second));
+    /// assert!(!first.eq_ignoring::<i32, _>(&second));
+    /// ```
+    fn eq_ignoring<'a, T, I>(&'a self, other: &'a Self) -> bool
+    where
+        Self::Ref<'a>: Remove<&'a T, I>,
+        <Self::Ref<'a> as Remove<&'a T, I>>::Remainder: PartialEq,
+        T: 'a,
+        I: Index;
+}
+
+impl<L> EqIgnoring for L
+where
+    L: ToRef,
+{
+    fn eq_ignoring<'a, T, I>(&'a self, other: &'a Self) -> bool
+    where
+        Self::Ref<'a>: Remove<&'a T, I>,
+        <Self::Ref<'a> as Remove<&'a T, I>>::Remainder: PartialEq,
+        T: 'a,
+        I: Index,
+    {
+        let (_, this) = self.to_ref().remove();
+        let (_, other) = other.to_ref().remove();
+        this == other
+    }
+}
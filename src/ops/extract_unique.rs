@@ -0,0 +1,88 @@
+use crate::HList;
+
+use super::{Index, NotContains, Remove};
+
+/// Move element out of the heterogenous list by type which appears in the list exactly once.
+///
+/// Generic parameter `W` is a witness that the remainder of the list
+/// does not contain another element of the same type, as described by [`NotContains`] trait.
+/// Witness cannot be named outside of this crate, so it is always inferred by the compiler,
+/// and the check cannot be skipped by specifying the witness explicitly.
+/// Like for [`Unique`](super::Unique) trait, the check relies on type inference,
+/// because Rust cannot check whether two types are not the same.
+pub trait ExtractUnique<W>: HList {
+    /// Moves element of the heterogenous list out by its type,
+    /// returning it together with the remainder of the list.
+    ///
+    /// Unlike [`Remove`] trait, the remainder of the list must not contain
+    /// an element of the same type, so the element must appear in the list exactly once,
+    /// whether its index is inferred or specified explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{ExtractUnique, Here}};
+    ///
+    /// let list = hlist![1_i32, true, "x"];
+    /// let (value, remainder) = list.extract_unique::<i32, _>();
+    /// assert_eq!(value, 1);
+    /// assert_eq!(remainder, hlist![true, "x"]);
+    ///
+    /// let (value, remainder) = list.extract_unique::<i32, Here>();
+    /// assert_eq!(value, 1);
+    /// assert_eq!(remainder, hlist![true, "x"]);
+    /// ```
+    ///
+    /// Element of the type which appears several times cannot be extracted:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::ExtractUnique};
+    ///
+    /// let list = hlist![1_i32, true, 2_i32];
+    /// let (value, remainder) = list.extract_unique::<i32, _>();
+    /// ```
+    ///
+    /// This holds even if the index of the element is specified explicitly:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{ExtractUnique, Here}};
+    ///
+    /// let list = hlist![1_i32, 2_i32];
+    /// let (value, remainder) = list.extract_unique::<i32, Here>();
+    /// ```
+    ///
+    /// Check cannot be skipped by specifying the witness explicitly either:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{ExtractUnique, Here}, Nil};
+    ///
+    /// let list = hlist![1_i32, 2_i32];
+    /// let (value, remainder) = ExtractUnique::<Nil>::extract_unique::<i32, Here>(list);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{ExtractUnique, Here}};
+    ///
+    /// let list = hlist![1_i32, 2_i32];
+    /// let (value, remainder) = ExtractUnique::<Here>::extract_unique::<i32, Here>(list);
+    /// ```
+    fn extract_unique<T, I>(self) -> (T, <Self as Remove<T, I>>::Remainder)
+    where
+        Self: Remove<T, I>,
+        <Self as Remove<T, I>>::Remainder: NotContains<T, W>,
+        I: Index;
+}
+
+impl<L, W> ExtractUnique<W> for L
+where
+    L: HList,
+{
+    fn extract_unique<T, I>(self) -> (T, <Self as Remove<T, I>>::Remainder)
+    where
+        Self: Remove<T, I>,
+        <Self as Remove<T, I>>::Remainder: NotContains<T, W>,
+        I: Index,
+    {
+        self.remove()
+    }
+}
@@ -0,0 +1,45 @@
+use alloc::vec::Vec;
+
+use crate::iter::Homogenous;
+
+/// Transform and filter elements of the homogenous list at the same time.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait FilterMap: Homogenous {
+    /// Transforms elements of the homogenous list with a closure,
+    /// collecting only those which were mapped into [`Some`] into a [vector](Vec).
+    ///
+    /// Resulting vector is returned because count of remaining elements
+    /// is known only at runtime. This mirrors [`Iterator::filter_map()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FilterMap};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let even = list.filter_map(|x| if x % 2 == 0 { Some(x * 10) } else { None });
+    /// assert_eq!(even, [20, 40]);
+    ///
+    /// let all = list.filter_map(|x| Some(x.to_string()));
+    /// assert_eq!(all, ["1", "2", "3", "4"]);
+    ///
+    /// let none = list.filter_map(|_| None::<i32>);
+    /// assert!(none.is_empty());
+    /// ```
+    fn filter_map<F, R>(self, f: F) -> Vec<R>
+    where
+        F: FnMut(Self::Item) -> Option<R>;
+}
+
+impl<T> FilterMap for T
+where
+    T: Homogenous,
+{
+    fn filter_map<F, R>(self, f: F) -> Vec<R>
+    where
+        F: FnMut(Self::Item) -> Option<R>,
+    {
+        self.into_iter().filter_map(f).collect()
+    }
+}
@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+
+use crate::iter::Homogenous;
+
+/// Flatten homogenous list of options into values which are present.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait FlattenOptions<T>: Homogenous<Item = Option<T>> {
+    /// Collects present values of the homogenous list of options into a [vector](Vec),
+    /// skipping all the `None` elements.
+    ///
+    /// Relative order of values is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FlattenOptions};
+    ///
+    /// let list = hlist![Some(1), None, Some(3)];
+    /// assert_eq!(list.flatten_options(), vec![1, 3]);
+    ///
+    /// let list = hlist![Some(1), Some(2)];
+    /// assert_eq!(list.flatten_options(), vec![1, 2]);
+    ///
+    /// let list = hlist![None::<i32>, None];
+    /// assert_eq!(list.flatten_options(), vec![]);
+    /// ```
+    fn flatten_options(self) -> Vec<T>;
+}
+
+impl<T, L> FlattenOptions<T> for L
+where
+    L: Homogenous<Item = Option<T>>,
+{
+    fn flatten_options(self) -> Vec<T> {
+        self.into_iter().flatten().collect()
+    }
+}
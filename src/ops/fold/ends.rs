@@ -0,0 +1,80 @@
+use crate::{ops::SplitEnds, Cons, HList, Nil};
+
+/// Fold elements of the homogenous list into an accumulator
+/// by pairs taken from both ends of the list.
+pub trait FoldEnds<Accumulator, Folder>: HList {
+    /// Folds elements into an accumulator by pairs taken from both ends of the list
+    /// by applying an operation via folder, returning the final result.
+    ///
+    /// The first element is paired with the last one, the second element
+    /// with the second to last one, and so on up to the middle of the list.
+    ///
+    /// If the list has an odd length, its middle element is paired with the clone of itself.
+    ///
+    /// # Examples
+    ///
+    /// Lists of even length are folded by pairs of distinct elements:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FoldEnds};
+    ///
+    /// let list = hlist![1, 2, 2, 1];
+    /// let is_palindrome = list.fold_ends(true, |acc, x, y| acc && x == y);
+    /// assert!(is_palindrome);
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let pairs = list.fold_ends(Vec::new(), |mut acc, x, y| {
+    ///     acc.push((x, y));
+    ///     acc
+    /// });
+    /// assert_eq!(pairs, [(1, 4), (2, 3)]);
+    /// ```
+    ///
+    /// Middle element of the list of odd length is paired with itself:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FoldEnds};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let pairs = list.fold_ends(Vec::new(), |mut acc, x, y| {
+    ///     acc.push((x, y));
+    ///     acc
+    /// });
+    /// assert_eq!(pairs, [(1, 3), (2, 2)]);
+    ///
+    /// let list = hlist![];
+    /// let is_palindrome = list.fold_ends(true, |acc, x: i32, y| acc && x == y);
+    /// assert!(is_palindrome);
+    /// ```
+    fn fold_ends(self, init: Accumulator, folder: Folder) -> Accumulator;
+}
+
+impl<A, F> FoldEnds<A, F> for Nil {
+    fn fold_ends(self, init: A, _: F) -> A {
+        init
+    }
+}
+
+impl<A, F, T> FoldEnds<A, F> for Cons<T, Nil>
+where
+    F: FnMut(A, T, T) -> A,
+    T: Clone,
+{
+    fn fold_ends(self, init: A, mut folder: F) -> A {
+        let Cons(middle, _) = self;
+        folder(init, middle.clone(), middle)
+    }
+}
+
+impl<A, F, T, Tail> FoldEnds<A, F> for Cons<T, Cons<T, Tail>>
+where
+    F: FnMut(A, T, T) -> A,
+    Self: SplitEnds<First = T, Last = T>,
+    <Self as SplitEnds>::Middle: FoldEnds<A, F>,
+{
+    fn fold_ends(self, init: A, mut folder: F) -> A {
+        let (first, middle, last) = self.split_ends();
+        let init = folder(init, first, last);
+        middle.fold_ends(init, folder)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Cons, HList, Nil};
+
+/// Fold every element of the heterogenous list into an accumulator
+/// together with the index of the element.
+pub trait FoldIndexed<Accumulator, Folder>: HList {
+    /// Folds every element into an accumulator together with the index of the element
+    /// by applying an operation via folder, returning the final result.
+    ///
+    /// This is the same as [`Fold::fold()`][fold], but index of each element
+    /// is passed into the folder along with accumulator and element itself.
+    /// Elements are folded from the beginning of the heterogenous list to the end.
+    ///
+    /// [fold]: crate::ops::Fold::fold()
+    ///
+    /// # Examples
+    ///
+    /// You can fold the list if it is homogenous (all elements have the same type):
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FoldIndexed};
+    ///
+    /// let list = hlist![10, 20, 30];
+    /// let folded = list.fold_indexed(0, |acc, i, x| acc + i * x);
+    /// assert_eq!(folded, 0 * 10 + 1 * 20 + 2 * 30);
+    /// ```
+    ///
+    /// Folding of heterogenous list is possible with heterogenous list of closures as folder:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FoldIndexed};
+    ///
+    /// let list = hlist![1, false, "hello world"];
+    /// let folded = list.fold_indexed(
+    ///     String::new(),
+    ///     hlist![
+    ///         |acc, i, x: i32| format!("{acc}{i}:{x};"),
+    ///         |acc, i, b: bool| format!("{acc}{i}:{b};"),
+    ///         |acc, i, s: &str| format!("{acc}{i}:{s}"),
+    ///     ],
+    /// );
+    /// assert_eq!(folded, "0:1;1:false;2:hello world");
+    /// ```
+    fn fold_indexed(self, init: Accumulator, folder: Folder) -> Accumulator;
+}
+
+impl<T, A, F> FoldIndexed<A, F> for T
+where
+    T: FoldIndexedFrom<A, F>,
+{
+    fn fold_indexed(self, init: A, folder: F) -> A {
+        self.fold_indexed_from(0, init, folder)
+    }
+}
+
+pub trait FoldIndexedFrom<Accumulator, Folder>: HList {
+    fn fold_indexed_from(self, index: usize, init: Accumulator, folder: Folder) -> Accumulator;
+}
+
+impl<A, F> FoldIndexedFrom<A, F> for Nil {
+    fn fold_indexed_from(self, _: usize, init: A, _: F) -> A {
+        init
+    }
+}
+
+impl<A, F, Head, Tail> FoldIndexedFrom<A, F> for Cons<Head, Tail>
+where
+    F: FnMut(A, usize, Head) -> A,
+    Tail: FoldIndexedFrom<A, F>,
+{
+    fn fold_indexed_from(self, index: usize, init: A, mut folder: F) -> A {
+        let Cons(head, tail) = self;
+        let init = folder(init, index, head);
+        tail.fold_indexed_from(index + 1, init, folder)
+    }
+}
+
+impl<A, FHead, FTail, Head, Tail> FoldIndexedFrom<A, Cons<FHead, FTail>> for Cons<Head, Tail>
+where
+    FHead: FnOnce(A, usize, Head) -> A,
+    Tail: FoldIndexedFrom<A, FTail>,
+{
+    fn fold_indexed_from(self, index: usize, init: A, folder: Cons<FHead, FTail>) -> A {
+        let Cons(head, tail) = self;
+        let Cons(folder_head, folder_tail) = folder;
+        let init = folder_head(init, index, head);
+        tail.fold_indexed_from(index + 1, init, folder_tail)
+    }
+}
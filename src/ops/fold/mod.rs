@@ -1,9 +1,15 @@
 pub use self::{
+    ends::FoldEnds,
     folder::{FoldFn, Folder},
+    indexed::FoldIndexed,
     left::Fold,
+    rest::FoldWithRest,
     right::RFold,
 };
 
+mod ends;
 mod folder;
+mod indexed;
 mod left;
+mod rest;
 mod right;
@@ -0,0 +1,84 @@
+use crate::{Cons, HList, Nil};
+
+/// Fold every element of the heterogenous list into an accumulator
+/// with access to the remaining part of the list.
+pub trait FoldWithRest<Accumulator, Folder>: HList {
+    /// Folds every element into an accumulator with access to the remaining part of the list
+    /// by applying an operation via folder, returning the final result.
+    ///
+    /// This is the same as [`Fold::fold()`][fold], but reference to the tail of the list
+    /// is passed into the folder along with accumulator and element itself,
+    /// which allows to look ahead on the next elements of the list.
+    /// Elements are folded from the beginning of the heterogenous list to the end.
+    ///
+    /// Type of the tail is different on each step, so folder
+    /// must be a heterogenous list of closures, one for each element.
+    ///
+    /// [fold]: crate::ops::Fold::fold()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FoldWithRest, HList, Nil};
+    ///
+    /// let list = hlist![1, 2.0, "three"];
+    /// let folded = list.fold_with_rest(
+    ///     Vec::new(),
+    ///     hlist![
+    ///         |mut acc: Vec<usize>, _: i32, rest: &HList![f64, &str]| {
+    ///             acc.push(rest.len());
+    ///             acc
+    ///         },
+    ///         |mut acc: Vec<usize>, _: f64, rest: &HList![&str]| {
+    ///             acc.push(rest.len());
+    ///             acc
+    ///         },
+    ///         |mut acc: Vec<usize>, _: &str, rest: &Nil| {
+    ///             acc.push(rest.len());
+    ///             acc
+    ///         },
+    ///     ],
+    /// );
+    /// assert_eq!(folded, [2, 1, 0]);
+    /// ```
+    ///
+    /// Remaining part of the list can be used to look ahead:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FoldWithRest, HList, Nil};
+    ///
+    /// let list = hlist!["key", '=', 42];
+    /// let folded = list.fold_with_rest(
+    ///     String::new(),
+    ///     hlist![
+    ///         |acc: String, key: &str, rest: &HList![char, i32]| {
+    ///             let sep = rest.head();
+    ///             format!("{acc}{key} (followed by {sep:?})")
+    ///         },
+    ///         |acc: String, sep: char, _: &HList![i32]| format!("{acc} {sep}"),
+    ///         |acc: String, value: i32, _: &Nil| format!("{acc} {value}"),
+    ///     ],
+    /// );
+    /// assert_eq!(folded, "key (followed by '=') = 42");
+    /// ```
+    fn fold_with_rest(self, init: Accumulator, folder: Folder) -> Accumulator;
+}
+
+impl<A> FoldWithRest<A, Nil> for Nil {
+    fn fold_with_rest(self, init: A, _: Nil) -> A {
+        init
+    }
+}
+
+impl<A, FHead, FTail, Head, Tail> FoldWithRest<A, Cons<FHead, FTail>> for Cons<Head, Tail>
+where
+    FHead: FnOnce(A, Head, &Tail) -> A,
+    Tail: FoldWithRest<A, FTail>,
+{
+    fn fold_with_rest(self, init: A, folder: Cons<FHead, FTail>) -> A {
+        let Cons(head, tail) = self;
+        let Cons(folder_head, folder_tail) = folder;
+        let init = folder_head(init, head, &tail);
+        tail.fold_with_rest(init, folder_tail)
+    }
+}
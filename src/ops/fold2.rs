@@ -0,0 +1,53 @@
+use crate::HList;
+
+use super::Fold;
+
+/// Fold every element of the heterogenous list into two accumulators simultaneously.
+pub trait Fold2: HList {
+    /// Folds every element into a pair of accumulators by applying an operation,
+    /// returning the final pair.
+    ///
+    /// This allows to compute two aggregates in one pass instead of two separate folds.
+    /// Folder can be either a closure or heterogenous list of closures,
+    /// just like for [`Fold`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Fold2};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let (sum, product) = list.fold2((0, 1), |(s, p), x| (s + x, p * x));
+    /// assert_eq!((sum, product), (10, 24));
+    /// ```
+    ///
+    /// Folding of heterogenous list is possible with heterogenous list of closures as folder:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Fold2};
+    ///
+    /// let list = hlist![1, 2.0, "three"];
+    /// let folder = hlist![
+    ///     |(count, len): (usize, usize), i: i32| (count + 1, len + i.to_string().len()),
+    ///     |(count, len): (usize, usize), f: f64| (count + 1, len + f.to_string().len()),
+    ///     |(count, len): (usize, usize), s: &str| (count + 1, len + s.len()),
+    /// ];
+    /// let (count, len) = list.fold2((0, 0), folder);
+    /// assert_eq!((count, len), (3, 7));
+    /// ```
+    fn fold2<A, B, F>(self, init: (A, B), f: F) -> (A, B)
+    where
+        Self: Fold<(A, B), F>;
+}
+
+impl<L> Fold2 for L
+where
+    L: HList,
+{
+    fn fold2<A, B, F>(self, init: (A, B), f: F) -> (A, B)
+    where
+        Self: Fold<(A, B), F>,
+    {
+        self.fold(init, f)
+    }
+}
@@ -0,0 +1,55 @@
+use core::fmt::Debug;
+
+use crate::{Cons, HList, Nil};
+
+/// Visit each element of the heterogenous list as a [`Debug`] trait object.
+pub trait ForEachDebug: HList {
+    /// Calls a closure on each element of the heterogenous list
+    /// coerced to the [`Debug`] trait object.
+    ///
+    /// Elements are visited from the beginning of the heterogenous list to the end.
+    /// Elements are not formatted eagerly, so the closure decides whether to format them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    ///
+    /// use hlist2::{hlist, ops::ForEachDebug};
+    ///
+    /// let list = hlist![1, 2.0, "three"];
+    /// let mut out = String::new();
+    /// list.for_each_debug(|item| write!(out, "{item:?};").unwrap());
+    /// assert_eq!(out, "1;2.0;\"three\";");
+    ///
+    /// let mut count = 0;
+    /// list.for_each_debug(|_| count += 1);
+    /// assert_eq!(count, 3);
+    /// ```
+    fn for_each_debug<F>(&self, f: F)
+    where
+        F: FnMut(&dyn Debug);
+}
+
+impl ForEachDebug for Nil {
+    fn for_each_debug<F>(&self, _: F)
+    where
+        F: FnMut(&dyn Debug),
+    {
+    }
+}
+
+impl<Head, Tail> ForEachDebug for Cons<Head, Tail>
+where
+    Head: Debug,
+    Tail: ForEachDebug,
+{
+    fn for_each_debug<F>(&self, mut f: F)
+    where
+        F: FnMut(&dyn Debug),
+    {
+        let Cons(head, tail) = self;
+        f(head);
+        tail.for_each_debug(f)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::HList;
+
+use super::Unfold;
+
+/// Construct homogenous heterogenous list where each element is produced from its index.
+pub trait FromIndexFn<F>: HList {
+    /// Constructs homogenous heterogenous list where each element is produced from its index.
+    ///
+    /// Function is called with indices `0, 1, 2, ...` up to the length of the list (excluding),
+    /// like in [`core::array::from_fn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::FromIndexFn};
+    ///
+    /// let list = <HList![i32, i32, i32]>::from_index_fn(|i| i as i32 * 2);
+    /// assert_eq!(list, hlist![0, 2, 4]);
+    ///
+    /// let list = <HList![]>::from_index_fn(|i| i);
+    /// assert_eq!(list, hlist![]);
+    /// ```
+    fn from_index_fn(f: F) -> Self;
+}
+
+impl<L, F, T> FromIndexFn<F> for L
+where
+    L: Unfold<T>,
+    F: FnMut(usize) -> T,
+{
+    fn from_index_fn(mut f: F) -> Self {
+        Self::unfold(0, |index| {
+            let item = f(*index);
+            *index += 1;
+            item
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Len;
+
+use super::Unfold;
+
+/// Create homogenous list of integers from a range.
+pub trait FromRange: Unfold<i64> + Len {
+    /// Creates a new homogenous list filled with integers of the half-open range `[START, END)`.
+    ///
+    /// Length of the range is known only when the type of the list is known,
+    /// so the caller must name the type of the list.
+    /// Length of the range must be equal to the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::FromRange, HList};
+    ///
+    /// let list = <HList![i64, i64, i64]>::from_range::<0, 3>();
+    /// assert_eq!(list, hlist![0, 1, 2]);
+    ///
+    /// let list: HList![i64, i64] = FromRange::from_range::<-5, -3>();
+    /// assert_eq!(list, hlist![-5, -4]);
+    ///
+    /// let list = <HList![]>::from_range::<42, 42>();
+    /// assert_eq!(list, hlist![]);
+    /// ```
+    ///
+    /// Length of the range must be equal to the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{ops::FromRange, HList};
+    ///
+    /// let list = <HList![i64, i64]>::from_range::<0, 3>();
+    /// ```
+    fn from_range<const START: i64, const END: i64>() -> Self;
+}
+
+impl<T> FromRange for T
+where
+    T: Unfold<i64> + Len,
+{
+    fn from_range<const START: i64, const END: i64>() -> Self {
+        const {
+            assert!(
+                START <= END && (END - START) as u64 == T::LEN as u64,
+                "length of the range must be equal to the list length"
+            )
+        };
+
+        Self::unfold(START, |next| {
+            let item = *next;
+            *next += 1;
+            item
+        })
+    }
+}
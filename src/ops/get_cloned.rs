@@ -0,0 +1,57 @@
+use crate::HList;
+
+use super::{Get, Index};
+
+/// Retrieve a clone of the element of the heterogenous list by type.
+///
+/// Rust has no negative trait bounds and no specialization,
+/// so absence of the type in the heterogenous list cannot be resolved at compile time.
+/// Because of this, there is no way to fall back to a default value,
+/// and the code which tries to retrieve the element of a type
+/// which is absent in the list will not compile.
+pub trait GetCloned: HList {
+    /// Retrieves a clone of the element of the heterogenous list by type.
+    ///
+    /// This is the same as cloning the reference returned by [`Get::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::GetCloned};
+    ///
+    /// let list = hlist![1, 2.0, String::from("hello")];
+    /// let string: String = list.get_cloned();
+    /// assert_eq!(string, "hello");
+    ///
+    /// let float: f64 = list.get_cloned();
+    /// assert_eq!(float, 2.0);
+    /// ```
+    ///
+    /// Element of provided type must be present in the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::GetCloned};
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let flag: bool = list.get_cloned();
+    /// ```
+    fn get_cloned<T, I>(&self) -> T
+    where
+        Self: Get<T, I>,
+        T: Clone,
+        I: Index;
+}
+
+impl<L> GetCloned for L
+where
+    L: HList,
+{
+    fn get_cloned<T, I>(&self) -> T
+    where
+        Self: Get<T, I>,
+        T: Clone,
+        I: Index,
+    {
+        self.get().clone()
+    }
+}
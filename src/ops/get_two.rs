@@ -0,0 +1,87 @@
+use super::{Get, Index, Remove, ToRef};
+
+/// Retrieve two elements of the heterogenous list by their types at once.
+pub trait GetTwo: ToRef {
+    /// Retrieves references to two elements of the heterogenous list by their types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::GetTwo};
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    /// let (a, b) = list.get_two::<i32, bool, _, _>();
+    /// assert_eq!((a, b), (&1, &true));
+    /// ```
+    fn get_two<A, B, IA, IB>(&self) -> (&A, &B)
+    where
+        Self: Get<A, IA> + Get<B, IB>,
+        IA: Index,
+        IB: Index;
+
+    /// Retrieves mutable references to two distinct elements of the heterogenous list
+    /// by their types.
+    ///
+    /// Second element is retrieved from the remaining part of the list
+    /// after the first element was excluded from it,
+    /// so both references can never point to the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::GetTwo};
+    ///
+    /// let mut list = hlist![1_i32, 2.0_f64, true];
+    /// let (a, b) = list.get_two_mut::<i32, bool, _, _>();
+    /// *a += 1;
+    /// *b = false;
+    /// assert_eq!(list, hlist![2, 2.0, false]);
+    /// ```
+    ///
+    /// Two mutable references to the same element cannot be retrieved:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::GetTwo};
+    ///
+    /// let mut list = hlist![1_i32, 2.0_f64, true];
+    /// let (a, b) = list.get_two_mut::<i32, i32, _, _>();
+    /// ```
+    fn get_two_mut<'a, A, B, IA, IB>(&'a mut self) -> (&'a mut A, &'a mut B)
+    where
+        Self::RefMut<'a>: Remove<&'a mut A, IA>,
+        <Self::RefMut<'a> as Remove<&'a mut A, IA>>::Remainder: Remove<&'a mut B, IB>,
+        A: 'a,
+        B: 'a,
+        IA: Index,
+        IB: Index;
+}
+
+impl<L> GetTwo for L
+where
+    L: ToRef,
+{
+    fn get_two<A, B, IA, IB>(&self) -> (&A, &B)
+    where
+        Self: Get<A, IA> + Get<B, IB>,
+        IA: Index,
+        IB: Index,
+    {
+        let a = Get::<A, IA>::get(self);
+        let b = Get::<B, IB>::get(self);
+        (a, b)
+    }
+
+    fn get_two_mut<'a, A, B, IA, IB>(&'a mut self) -> (&'a mut A, &'a mut B)
+    where
+        Self::RefMut<'a>: Remove<&'a mut A, IA>,
+        <Self::RefMut<'a> as Remove<&'a mut A, IA>>::Remainder: Remove<&'a mut B, IB>,
+        A: 'a,
+        B: 'a,
+        IA: Index,
+        IB: Index,
+    {
+        let (a, remainder) = self.to_mut().remove();
+        let (b, _) = remainder.remove();
+        (a, b)
+    }
+}
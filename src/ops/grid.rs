@@ -0,0 +1,64 @@
+use crate::Len;
+
+use super::{FromIndexFn, Unfold};
+
+/// Create homogenous list of homogenous lists (grid) from a function of two indices.
+pub trait Grid<Row, T>: Unfold<Row> + Len
+where
+    Row: Unfold<T> + Len,
+{
+    /// Creates homogenous list of `R` rows, where each row is homogenous list of `C` elements.
+    ///
+    /// Function is called for each element with its row index and column index,
+    /// in row-major order.
+    /// Count of rows and columns must be equal to the lengths of the list and its rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::Grid};
+    ///
+    /// type Row = HList![usize, usize, usize];
+    ///
+    /// let mut calls = Vec::new();
+    /// let grid = <HList![Row, Row]>::grid::<2, 3, _>(|r, c| {
+    ///     calls.push((r, c));
+    ///     r * 10 + c
+    /// });
+    /// assert_eq!(grid, hlist![hlist![0, 1, 2], hlist![10, 11, 12]]);
+    /// assert_eq!(calls, [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    /// ```
+    ///
+    /// Count of rows and columns must match the type of the grid:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{HList, ops::Grid};
+    ///
+    /// type Row = HList![usize, usize, usize];
+    ///
+    /// let grid = <HList![Row, Row]>::grid::<3, 2, _>(|r, c| r * 10 + c);
+    /// ```
+    fn grid<const R: usize, const C: usize, F>(f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T;
+}
+
+impl<L, Row, T> Grid<Row, T> for L
+where
+    L: Unfold<Row> + Len,
+    Row: Unfold<T> + Len,
+{
+    fn grid<const R: usize, const C: usize, F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        const {
+            assert!(
+                R == L::LEN && C == Row::LEN,
+                "count of rows and columns must be equal to the lengths of the grid"
+            )
+        };
+
+        Self::from_index_fn(|r| Row::from_index_fn(|c| f(r, c)))
+    }
+}
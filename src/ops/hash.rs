@@ -0,0 +1,66 @@
+use core::hash::{Hash, Hasher};
+
+use crate::HList;
+
+/// Compute hash of the heterogenous list without providing a hasher.
+pub trait HashValue: HList + Hash {
+    /// Computes hash of the heterogenous list as a single [`u64`] value.
+    ///
+    /// Hash is computed with [FNV-1a] algorithm,
+    /// so resulting value is stable for the same values and the same platform.
+    ///
+    /// Note that this hash is not resistant to HashDoS attacks.
+    ///
+    /// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::HashValue};
+    ///
+    /// let list = hlist![1, 'a', "hello"];
+    /// assert_eq!(list.hash_value(), hlist![1, 'a', "hello"].hash_value());
+    /// assert_ne!(list.hash_value(), hlist![2, 'a', "hello"].hash_value());
+    /// assert_ne!(list.hash_value(), hlist![1, 'b', "hello"].hash_value());
+    /// ```
+    fn hash_value(&self) -> u64;
+}
+
+impl<T> HashValue for T
+where
+    T: HList + Hash,
+{
+    fn hash_value(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+}
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        let Self(hash) = *self;
+        hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let Self(hash) = self;
+        for &byte in bytes {
+            *hash ^= u64::from(byte);
+            *hash = hash.wrapping_mul(Self::PRIME);
+        }
+    }
+}
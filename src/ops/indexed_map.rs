@@ -0,0 +1,42 @@
+use alloc::collections::BTreeMap;
+
+use crate::{Cons, HList, Nil};
+
+/// Convert homogenous list into a [map](BTreeMap) keyed by indices of elements.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait IntoIndexedMap<T>: HList {
+    /// Converts homogenous list into a [map](BTreeMap)
+    /// where each element is keyed by its index in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use hlist2::{hlist, ops::IntoIndexedMap};
+    ///
+    /// let map = hlist![10, 20, 30].into_indexed_map();
+    /// assert_eq!(map, BTreeMap::from([(0, 10), (1, 20), (2, 30)]));
+    ///
+    /// let map: BTreeMap<usize, i32> = hlist![].into_indexed_map();
+    /// assert!(map.is_empty());
+    /// ```
+    fn into_indexed_map(self) -> BTreeMap<usize, T>;
+}
+
+impl<T> IntoIndexedMap<T> for Nil {
+    fn into_indexed_map(self) -> BTreeMap<usize, T> {
+        BTreeMap::new()
+    }
+}
+
+impl<Head, Tail> IntoIndexedMap<Head> for Cons<Head, Tail>
+where
+    Self: IntoIterator<Item = Head>,
+    Tail: HList,
+{
+    fn into_indexed_map(self) -> BTreeMap<usize, Head> {
+        self.into_iter().enumerate().collect()
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+
+use crate::iter::Homogenous;
+
+/// Interleave elements of two homogenous lists of the same element type.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait InterleaveHomogenous: Homogenous {
+    /// Collects elements of two homogenous lists into a [vector](Vec),
+    /// alternating elements of this list and the other list.
+    ///
+    /// Lists can have different lengths: after the shorter list is exhausted,
+    /// the remaining elements of the longer list are appended to the end of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::InterleaveHomogenous};
+    ///
+    /// let list = hlist![1, 3, 5];
+    /// assert_eq!(list.interleave_homogenous(hlist![2, 4, 6]), [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(list.interleave_homogenous(hlist![2, 4]), [1, 2, 3, 4, 5]);
+    /// assert_eq!(list.interleave_homogenous(hlist![2]), [1, 2, 3, 5]);
+    ///
+    /// let list = hlist![1];
+    /// assert_eq!(list.interleave_homogenous(hlist![2, 4, 6]), [1, 2, 4, 6]);
+    /// ```
+    fn interleave_homogenous<Other>(self, other: Other) -> Vec<Self::Item>
+    where
+        Other: Homogenous<Item = Self::Item>;
+}
+
+impl<T> InterleaveHomogenous for T
+where
+    T: Homogenous,
+{
+    fn interleave_homogenous<Other>(self, other: Other) -> Vec<Self::Item>
+    where
+        Other: Homogenous<Item = Self::Item>,
+    {
+        let mut this = self.into_iter();
+        let mut other = other.into_iter();
+
+        let mut vec = Vec::new();
+        loop {
+            match (this.next(), other.next()) {
+                (Some(a), Some(b)) => vec.extend([a, b]),
+                (Some(a), None) => {
+                    vec.push(a);
+                    vec.extend(this);
+                    break;
+                }
+                (None, Some(b)) => {
+                    vec.push(b);
+                    vec.extend(other);
+                    break;
+                }
+                (None, None) => break,
+            }
+        }
+        vec
+    }
+}
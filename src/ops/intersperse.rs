@@ -0,0 +1,69 @@
+use crate::{Cons, HList, Nil};
+
+/// Place elements of separator heterogenous list between adjacent elements of the heterogenous list.
+pub trait InterspersedWith<Separators>: HList
+where
+    Separators: HList,
+{
+    /// Type of new heterogenous list with separators between elements.
+    type Output: HList;
+
+    /// Places elements of separator heterogenous list between adjacent elements of the heterogenous list.
+    ///
+    /// The first separator is placed between the first and the second element,
+    /// the second separator is placed between the second and the third element, and so on.
+    /// So length of separator list must be less than the length of the list by one
+    /// (or both lists should be empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::InterspersedWith};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let interspersed = list.interspersed_with(hlist![",", ';']);
+    /// assert_eq!(interspersed, hlist![1, ",", 2, ';', 3]);
+    /// ```
+    ///
+    /// Length of separator list must be less than the length of the list by one:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::InterspersedWith};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let interspersed = list.interspersed_with(hlist![",", ";", "."]);
+    /// ```
+    fn interspersed_with(self, separators: Separators) -> Self::Output;
+}
+
+impl InterspersedWith<Nil> for Nil {
+    type Output = Nil;
+
+    fn interspersed_with(self, _: Nil) -> Self::Output {
+        self
+    }
+}
+
+impl<Head> InterspersedWith<Nil> for Cons<Head, Nil> {
+    type Output = Self;
+
+    fn interspersed_with(self, _: Nil) -> Self::Output {
+        self
+    }
+}
+
+impl<Head, Next, Tail, SHead, STail> InterspersedWith<Cons<SHead, STail>>
+    for Cons<Head, Cons<Next, Tail>>
+where
+    Cons<Next, Tail>: InterspersedWith<STail>,
+    STail: HList,
+{
+    type Output = Cons<Head, Cons<SHead, <Cons<Next, Tail> as InterspersedWith<STail>>::Output>>;
+
+    fn interspersed_with(self, separators: Cons<SHead, STail>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let Cons(separator, separators) = separators;
+        let tail = tail.interspersed_with(separators);
+        Cons(head, Cons(separator, tail))
+    }
+}
@@ -0,0 +1,36 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{iter::Homogenous, Len};
+
+/// Convert homogenous heterogenous list into a [boxed slice](Box).
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait IntoBoxedSlice: Homogenous + Len {
+    /// Converts homogenous heterogenous list into a [boxed slice](Box)
+    /// with length equal to the length of the list.
+    ///
+    /// Order of elements in the slice is the same as in the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::IntoBoxedSlice};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let slice: Box<[i32]> = list.into_boxed_slice();
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(*slice, [1, 2, 3]);
+    /// ```
+    fn into_boxed_slice(self) -> Box<[Self::Item]>;
+}
+
+impl<T> IntoBoxedSlice for T
+where
+    T: Homogenous + Len,
+{
+    fn into_boxed_slice(self) -> Box<[Self::Item]> {
+        let mut vec = Vec::with_capacity(T::LEN);
+        vec.extend(self);
+        vec.into_boxed_slice()
+    }
+}
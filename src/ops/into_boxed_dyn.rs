@@ -0,0 +1,77 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::HList;
+
+use super::CollectInto;
+
+/// Convert the heterogenous list into a [vector](Vec) of [boxed](Box) trait objects.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait IntoBoxedDyn<Trait, Mapper>: HList
+where
+    Trait: ?Sized,
+{
+    /// Converts the heterogenous list into a [vector](Vec) of [boxed](Box) trait objects
+    /// by boxing each element with mapper.
+    ///
+    /// Stable Rust cannot express that any type can be coerced into some trait object
+    /// provided as a generic parameter, so mapper is responsible for boxing each element
+    /// and coercing the box into the trait object. Usually this is done with
+    /// [mapper function](super::MapFn) which is implemented for all types implementing the trait.
+    ///
+    /// Order of elements in the vector is the same as in the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Display;
+    ///
+    /// use hlist2::{
+    ///     hlist,
+    ///     ops::{IntoBoxedDyn, MapFn, Mapper},
+    /// };
+    ///
+    /// struct BoxDisplay;
+    ///
+    /// impl<T> MapFn<T> for BoxDisplay
+    /// where
+    ///     T: Display + 'static,
+    /// {
+    ///     type Output = Box<dyn Display>;
+    ///     fn map(&mut self, value: T) -> Self::Output { Box::new(value) }
+    /// }
+    ///
+    /// let list = hlist![1_i32, 2.5_f64, "three"];
+    /// let boxed: Vec<Box<dyn Display>> = list.into_boxed_dyn(Mapper(BoxDisplay));
+    /// let strings: Vec<_> = boxed.iter().map(|item| item.to_string()).collect();
+    /// assert_eq!(strings, ["1", "2.5", "three"]);
+    /// ```
+    ///
+    /// Heterogenous list of closures can be used as mapper too:
+    ///
+    /// ```
+    /// use core::fmt::Debug;
+    ///
+    /// use hlist2::{hlist, ops::IntoBoxedDyn};
+    ///
+    /// let list = hlist![1, Some(true)];
+    /// let boxed = list.into_boxed_dyn(
+    ///     hlist![
+    ///         |i: i32| Box::new(i) as Box<dyn Debug>,
+    ///         |b: Option<bool>| Box::new(b) as Box<dyn Debug>,
+    ///     ]
+    /// );
+    /// assert_eq!(format!("{boxed:?}"), "[1, Some(true)]");
+    /// ```
+    fn into_boxed_dyn(self, mapper: Mapper) -> Vec<Box<Trait>>;
+}
+
+impl<L, Trait, M> IntoBoxedDyn<Trait, M> for L
+where
+    L: CollectInto<Box<Trait>, M>,
+    Trait: ?Sized,
+{
+    fn into_boxed_dyn(self, mapper: M) -> Vec<Box<Trait>> {
+        self.collect_into(mapper)
+    }
+}
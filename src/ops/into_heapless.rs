@@ -0,0 +1,58 @@
+use heapless::Vec;
+
+use crate::{iter::Homogenous, Len};
+
+/// Convert homogenous heterogenous list into a [fixed-capacity vector](Vec).
+///
+/// This trait is available only with `heapless` feature enabled.
+pub trait IntoHeapless: Homogenous + Len {
+    /// Converts homogenous heterogenous list into a [fixed-capacity vector](Vec)
+    /// with capacity of `N`.
+    ///
+    /// Capacity of the vector must be greater than or equal to the length of the list.
+    /// Order of elements in the vector is the same as in the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::IntoHeapless};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let vec: heapless::Vec<i32, 4> = list.into_heapless::<4>();
+    /// assert_eq!(vec.len(), 3);
+    /// assert_eq!(vec.capacity(), 4);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    ///
+    /// Capacity of the vector cannot be less than the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::IntoHeapless};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let vec = list.into_heapless::<2>();
+    /// ```
+    fn into_heapless<const N: usize>(self) -> Vec<Self::Item, N>;
+}
+
+impl<T> IntoHeapless for T
+where
+    T: Homogenous + Len,
+{
+    fn into_heapless<const N: usize>(self) -> Vec<Self::Item, N> {
+        const {
+            assert!(
+                N >= T::LEN,
+                "capacity of a vector must be greater than or equal to the list length"
+            )
+        };
+
+        let mut vec = Vec::new();
+        for item in self {
+            if vec.push(item).is_err() {
+                unreachable!("capacity was checked before")
+            }
+        }
+        vec
+    }
+}
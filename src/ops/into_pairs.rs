@@ -0,0 +1,59 @@
+use alloc::vec::Vec;
+
+use crate::{iter::Homogenous, Len};
+
+use super::SameLenAs;
+
+/// Pair values of the homogenous list with keys of another homogenous list.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait IntoPairs: Homogenous + Len {
+    /// Pairs each value of the homogenous list with a key at the same position
+    /// of another homogenous list, collecting key-value pairs into a [vector](Vec).
+    ///
+    /// Both lists must have the same length.
+    /// Resulting vector is ready to be collected into a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use hlist2::{hlist, ops::IntoPairs};
+    ///
+    /// let values = hlist![10, 20];
+    /// let pairs = values.into_pairs(hlist!["a", "b"]);
+    /// assert_eq!(pairs, vec![("a", 10), ("b", 20)]);
+    ///
+    /// let map = HashMap::<_, _>::from_iter(pairs);
+    /// assert_eq!(map["b"], 20);
+    /// ```
+    ///
+    /// Lists of different lengths cannot be paired:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::IntoPairs};
+    ///
+    /// let values = hlist![10, 20];
+    /// let pairs = values.into_pairs(hlist!["a", "b", "c"]);
+    /// ```
+    fn into_pairs<Keys>(self, keys: Keys) -> Vec<(Keys::Item, Self::Item)>
+    where
+        Keys: Homogenous,
+        Self: SameLenAs<Keys>;
+}
+
+impl<T> IntoPairs for T
+where
+    T: Homogenous + Len,
+{
+    fn into_pairs<Keys>(self, keys: Keys) -> Vec<(Keys::Item, Self::Item)>
+    where
+        Keys: Homogenous,
+        Self: SameLenAs<Keys>,
+    {
+        let mut pairs = Vec::with_capacity(T::LEN);
+        pairs.extend(keys.into_iter().zip(self));
+        pairs
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{Cons, HList, Nil};
+
+/// Construct homogenous heterogenous list where each element depends on the previous one.
+pub trait Iterate<T>: HList {
+    /// Constructs homogenous heterogenous list where the first element is the seed
+    /// and each next element is the result of the function applied to the previous one.
+    ///
+    /// Function is called exactly once per each element except the first one,
+    /// so the last element of the list is never passed into the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Iterate, HList};
+    ///
+    /// let list = <HList![i32, i32, i32, i32]>::iterate(1, |x| x * 2);
+    /// assert_eq!(list, hlist![1, 2, 4, 8]);
+    ///
+    /// let mut calls = 0;
+    /// let list = <HList![(u64, u64), (u64, u64), (u64, u64), (u64, u64)]>::iterate(
+    ///     (0, 1),
+    ///     |&(a, b)| {
+    ///         calls += 1;
+    ///         (b, a + b)
+    ///     },
+    /// );
+    /// assert_eq!(list, hlist![(0, 1), (1, 1), (1, 2), (2, 3)]);
+    /// assert_eq!(calls, 3);
+    ///
+    /// let list = <HList![]>::iterate(1, |x| x * 2);
+    /// assert_eq!(list, hlist![]);
+    /// ```
+    fn iterate<F>(seed: T, f: F) -> Self
+    where
+        F: FnMut(&T) -> T;
+}
+
+impl<T> Iterate<T> for Nil {
+    fn iterate<F>(_: T, _: F) -> Self
+    where
+        F: FnMut(&T) -> T,
+    {
+        Nil
+    }
+}
+
+impl<T> Iterate<T> for Cons<T, Nil> {
+    fn iterate<F>(seed: T, _: F) -> Self
+    where
+        F: FnMut(&T) -> T,
+    {
+        Cons(seed, Nil)
+    }
+}
+
+impl<T, Tail> Iterate<T> for Cons<T, Cons<T, Tail>>
+where
+    Cons<T, Tail>: Iterate<T>,
+{
+    fn iterate<F>(seed: T, mut f: F) -> Self
+    where
+        F: FnMut(&T) -> T,
+    {
+        let next = f(&seed);
+        let tail = Cons::iterate(next, f);
+        Cons(seed, tail)
+    }
+}
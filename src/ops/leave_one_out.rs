@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+
+use crate::{Cons, HList, Nil};
+
+/// Pair each element position of the homogenous list with all the other elements.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait LeaveOneOut: HList {
+    /// Type of new heterogenous list of vectors.
+    type Output: HList;
+
+    /// Produces new heterogenous list where each element is a [vector](Vec)
+    /// of all the other elements of the homogenous list, leaving out an element at the same position.
+    ///
+    /// Relative order of elements is preserved in each vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::LeaveOneOut};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let list = list.leave_one_out();
+    /// assert_eq!(list, hlist![vec![2, 3], vec![1, 3], vec![1, 2]]);
+    ///
+    /// let list = hlist!["single"];
+    /// let list = list.leave_one_out();
+    /// assert_eq!(list, hlist![vec![]]);
+    /// ```
+    fn leave_one_out(&self) -> Self::Output;
+}
+
+impl LeaveOneOut for Nil {
+    type Output = Nil;
+
+    fn leave_one_out(&self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<Head, Tail> LeaveOneOut for Cons<Head, Tail>
+where
+    Head: Clone,
+    Self: LeaveOneOutFrom<Head>,
+    for<'a> &'a Self: IntoIterator<Item = &'a Head>,
+{
+    type Output = <Self as LeaveOneOutFrom<Head>>::Output;
+
+    fn leave_one_out(&self) -> Self::Output {
+        let all: Vec<_> = self.into_iter().collect();
+        self.leave_one_out_from(&all, 0)
+    }
+}
+
+pub trait LeaveOneOutFrom<T>: HList {
+    type Output: HList;
+
+    fn leave_one_out_from(&self, all: &[&T], index: usize) -> Self::Output;
+}
+
+impl<T> LeaveOneOutFrom<T> for Nil {
+    type Output = Nil;
+
+    fn leave_one_out_from(&self, _: &[&T], _: usize) -> Self::Output {
+        Nil
+    }
+}
+
+impl<T, Tail> LeaveOneOutFrom<T> for Cons<T, Tail>
+where
+    T: Clone,
+    Tail: LeaveOneOutFrom<T>,
+{
+    type Output = Cons<Vec<T>, Tail::Output>;
+
+    fn leave_one_out_from(&self, all: &[&T], index: usize) -> Self::Output {
+        let Cons(_, tail) = self;
+        let (before, after) = all.split_at(index);
+        let others = before.iter().chain(&after[1..]);
+        let head = others.map(|&item| item.clone()).collect();
+        let tail = tail.leave_one_out_from(all, index + 1);
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,41 @@
+use core::cmp::Ordering;
+
+use crate::Len;
+
+/// Compare lengths (counts of elements) of two heterogenous lists at compile time.
+pub trait LenCmp<Other>: Len
+where
+    Other: Len,
+{
+    /// Result of comparison of the length of this heterogenous list
+    /// with the length of other heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use hlist2::{ops::LenCmp, HList};
+    ///
+    /// assert_eq!(<HList![i32] as LenCmp<HList![i32, f64]>>::ORDERING, Ordering::Less);
+    /// assert_eq!(<HList![i32, bool] as LenCmp<HList![f64, &str]>>::ORDERING, Ordering::Equal);
+    /// assert_eq!(<HList![i32, bool] as LenCmp<HList![]>>::ORDERING, Ordering::Greater);
+    /// ```
+    const ORDERING: Ordering;
+}
+
+impl<T, Other> LenCmp<Other> for T
+where
+    T: Len,
+    Other: Len,
+{
+    const ORDERING: Ordering = {
+        if T::LEN < Other::LEN {
+            Ordering::Less
+        } else if T::LEN > Other::LEN {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    };
+}
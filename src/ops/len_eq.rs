@@ -0,0 +1,94 @@
+use crate::{Cons, Len, Nil};
+
+/// Heterogenous list with length (count of elements) equal to provided constant.
+///
+/// Rust cannot compare constant generic parameter with an associated constant in trait bounds,
+/// so this trait is implemented recursively for lists with length of 32 and less:
+/// [`Nil`] has length of `0`, and [`Cons`] has length of `N + 1` if its tail has length of `N`.
+///
+/// # Limitations
+///
+/// Bound `LenEq<N>` cannot be satisfied for `N` greater than 32,
+/// even if the list has exactly `N` elements.
+/// A blanket implementation guarded by `const { assert!(Self::LEN == N) }` is not possible here
+/// because this trait has no methods: constant assertion is evaluated only in function bodies,
+/// so such implementation would satisfy the bound for lists of any length.
+/// To check length of longer lists, compare [`LEN`](Len::LEN) in a constant context instead.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{hlist, ops::LenEq};
+///
+/// fn exactly_three<L>(list: L) -> L
+/// where
+///     L: LenEq<3>,
+/// {
+///     list
+/// }
+///
+/// let list = exactly_three(hlist![1, 2.0, true]);
+/// assert_eq!(list, hlist![1, 2.0, true]);
+/// ```
+///
+/// Lists of other lengths do not satisfy such bound:
+///
+/// ```compile_fail
+/// use hlist2::{hlist, ops::LenEq};
+///
+/// fn exactly_three<L>(list: L) -> L
+/// where
+///     L: LenEq<3>,
+/// {
+///     list
+/// }
+///
+/// let list = exactly_three(hlist![1, 2.0]);
+/// ```
+pub trait LenEq<const N: usize>: Len {}
+
+impl LenEq<0> for Nil {}
+
+macro_rules! len_eq {
+    ($($tail:literal => $len:literal),* $(,)?) => {
+        $(
+            impl<Head, Tail> LenEq<$len> for Cons<Head, Tail> where Tail: LenEq<$tail> {}
+        )*
+    };
+}
+
+// Equality of length is implemented for lists of length 32 and less
+len_eq!(
+    0 => 1,
+    1 => 2,
+    2 => 3,
+    3 => 4,
+    4 => 5,
+    5 => 6,
+    6 => 7,
+    7 => 8,
+    8 => 9,
+    9 => 10,
+    10 => 11,
+    11 => 12,
+    12 => 13,
+    13 => 14,
+    14 => 15,
+    15 => 16,
+    16 => 17,
+    17 => 18,
+    18 => 19,
+    19 => 20,
+    20 => 21,
+    21 => 22,
+    22 => 23,
+    23 => 24,
+    24 => 25,
+    25 => 26,
+    26 => 27,
+    27 => 28,
+    28 => 29,
+    29 => 30,
+    30 => 31,
+    31 => 32,
+);
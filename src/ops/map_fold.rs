@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+
+use crate::iter::Homogenous;
+
+/// Fold elements of the homogenous list into an accumulator while emitting outputs.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait MapFold: Homogenous {
+    /// Folds every element into an accumulator by applying an operation,
+    /// optionally emitting an output for each element.
+    ///
+    /// Closure receives mutable reference to the accumulator, so it can update the state,
+    /// and returns [`Some`] to emit an output or [`None`] to skip the element.
+    /// Returns final state of the accumulator together with a [vector](Vec) of emitted outputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapFold};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let (sum, emitted) = list.mapfold(0, |sum, x| {
+    ///     *sum += x;
+    ///     if x % 2 == 0 { Some(*sum) } else { None }
+    /// });
+    /// assert_eq!(sum, 10);
+    /// assert_eq!(emitted, [3, 10]);
+    ///
+    /// let (count, emitted) = list.mapfold(0, |count, _| {
+    ///     *count += 1;
+    ///     None::<()>
+    /// });
+    /// assert_eq!(count, 4);
+    /// assert!(emitted.is_empty());
+    /// ```
+    fn mapfold<A, R, F>(self, init: A, f: F) -> (A, Vec<R>)
+    where
+        F: FnMut(&mut A, Self::Item) -> Option<R>;
+}
+
+impl<T> MapFold for T
+where
+    T: Homogenous,
+{
+    fn mapfold<A, R, F>(self, init: A, mut f: F) -> (A, Vec<R>)
+    where
+        F: FnMut(&mut A, Self::Item) -> Option<R>,
+    {
+        let mut state = init;
+        let emitted = self
+            .into_iter()
+            .filter_map(|item| f(&mut state, item))
+            .collect();
+        (state, emitted)
+    }
+}
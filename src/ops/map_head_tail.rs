@@ -0,0 +1,54 @@
+use crate::{Cons, HList};
+
+use super::TailOf;
+
+/// Transform the head and the tail of the heterogenous list at once.
+///
+/// Types of the head and the tail are named by [`TailOf`] trait.
+pub trait MapHeadTail: TailOf {
+    /// Transforms the heterogenous list into another heterogenous list
+    /// by applying the first closure to its head and the second closure to its tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{MapHeadTail, Reverse}, Cons};
+    ///
+    /// let list = Cons(1, hlist![2.0, true]);
+    /// let list = list.map_head_tail(|x| x as f64, |tail| tail.reverse());
+    /// assert_eq!(list, hlist![1.0, true, 2.0]);
+    /// ```
+    ///
+    /// Tail can be transformed into a heterogenous list of any other type:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Append, MapHeadTail}, Cons, Nil};
+    ///
+    /// let list = hlist![1];
+    /// let list: Cons<String, Cons<&str, Nil>> =
+    ///     list.map_head_tail(|x| x.to_string(), |tail| tail.append("tail"));
+    /// assert_eq!(list, hlist![String::from("1"), "tail"]);
+    /// ```
+    fn map_head_tail<FH, FT, R, RT>(self, fh: FH, ft: FT) -> Cons<R, RT>
+    where
+        FH: FnOnce(Self::Head) -> R,
+        FT: FnOnce(Self::Tail) -> RT,
+        RT: HList;
+}
+
+impl<Head, Tail> MapHeadTail for Cons<Head, Tail>
+where
+    Tail: HList,
+{
+    fn map_head_tail<FH, FT, R, RT>(self, fh: FH, ft: FT) -> Cons<R, RT>
+    where
+        FH: FnOnce(Self::Head) -> R,
+        FT: FnOnce(Self::Tail) -> RT,
+        RT: HList,
+    {
+        let Cons(head, tail) = self;
+        let head = fh(head);
+        let tail = ft(tail);
+        Cons(head, tail)
+    }
+}
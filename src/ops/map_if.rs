@@ -0,0 +1,58 @@
+use crate::{Cons, HList, Nil};
+
+/// Conditionally transform elements of the homogenous list, preserving its type.
+pub trait MapIf<T>: HList {
+    /// Transforms each element of the homogenous list satisfying the predicate,
+    /// leaving other elements unchanged.
+    ///
+    /// Type of the list is preserved because the transformation
+    /// must produce a value of the same type as an element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::MapIf};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let list: HList![i32, i32, i32, i32] = list.map_if(|x| x % 2 == 0, |x| x * 10);
+    /// assert_eq!(list, hlist![1, 20, 3, 40]);
+    ///
+    /// let mut calls = 0;
+    /// let list = list.map_if(|&x| x > 100, |x| {
+    ///     calls += 1;
+    ///     x
+    /// });
+    /// assert_eq!(list, hlist![1, 20, 3, 40]);
+    /// assert_eq!(calls, 0);
+    /// ```
+    fn map_if<P, F>(self, pred: P, f: F) -> Self
+    where
+        P: FnMut(&T) -> bool,
+        F: FnMut(T) -> T;
+}
+
+impl<T> MapIf<T> for Nil {
+    fn map_if<P, F>(self, _: P, _: F) -> Self
+    where
+        P: FnMut(&T) -> bool,
+        F: FnMut(T) -> T,
+    {
+        self
+    }
+}
+
+impl<T, Tail> MapIf<T> for Cons<T, Tail>
+where
+    Tail: MapIf<T>,
+{
+    fn map_if<P, F>(self, mut pred: P, mut f: F) -> Self
+    where
+        P: FnMut(&T) -> bool,
+        F: FnMut(T) -> T,
+    {
+        let Cons(head, tail) = self;
+        let head = if pred(&head) { f(head) } else { head };
+        let tail = tail.map_if(pred, f);
+        Cons(head, tail)
+    }
+}
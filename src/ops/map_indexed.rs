@@ -0,0 +1,94 @@
+use crate::{Cons, HList, Nil};
+
+/// Transform one heterogenous list into another with indices of elements.
+pub trait MapIndexed<Mapper>: HList {
+    /// Type of new heterogenous list after transformation.
+    type Output: HList;
+
+    /// Transforms the heterogenous list into another heterogenous list
+    /// by applying an operation to each element together with its index by mapper.
+    ///
+    /// # Examples
+    ///
+    /// You can map the list if it is homogenous (all elements have the same type):
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapIndexed};
+    ///
+    /// let list = hlist![10, 20, 30];
+    /// let list = list.map_indexed(|i, x| x + i);
+    /// assert_eq!(list, hlist![10, 21, 32]);
+    /// ```
+    ///
+    /// Mapping of heterogenous list is possible with heterogenous list of closures as mapper:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapIndexed};
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let list = list.map_indexed(
+    ///     hlist![
+    ///         |i, x: i32| (i, x + 1),
+    ///         |i, y: f64| (i, y * 2.0),
+    ///     ],
+    /// );
+    /// assert_eq!(list, hlist![(0, 2), (1, 4.0)]);
+    /// ```
+    fn map_indexed(self, mapper: Mapper) -> Self::Output;
+}
+
+impl<T, M> MapIndexed<M> for T
+where
+    T: MapIndexedFrom<M>,
+{
+    type Output = T::Output;
+
+    fn map_indexed(self, mapper: M) -> Self::Output {
+        self.map_indexed_from(0, mapper)
+    }
+}
+
+pub trait MapIndexedFrom<Mapper>: HList {
+    type Output: HList;
+
+    fn map_indexed_from(self, index: usize, mapper: Mapper) -> Self::Output;
+}
+
+impl<M> MapIndexedFrom<M> for Nil {
+    type Output = Nil;
+
+    fn map_indexed_from(self, _: usize, _: M) -> Self::Output {
+        self
+    }
+}
+
+impl<M, R, Head, Tail> MapIndexedFrom<M> for Cons<Head, Tail>
+where
+    M: FnMut(usize, Head) -> R,
+    Tail: MapIndexedFrom<M>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_indexed_from(self, index: usize, mut mapper: M) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = mapper(index, head);
+        let tail = tail.map_indexed_from(index + 1, mapper);
+        Cons(head, tail)
+    }
+}
+
+impl<MHead, MTail, R, Head, Tail> MapIndexedFrom<Cons<MHead, MTail>> for Cons<Head, Tail>
+where
+    MHead: FnOnce(usize, Head) -> R,
+    Tail: MapIndexedFrom<MTail>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_indexed_from(self, index: usize, mapper: Cons<MHead, MTail>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let Cons(mapper_head, mapper_tail) = mapper;
+        let head = mapper_head(index, head);
+        let tail = tail.map_indexed_from(index + 1, mapper_tail);
+        Cons(head, tail)
+    }
+}
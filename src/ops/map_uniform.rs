@@ -0,0 +1,124 @@
+use crate::{Cons, HList, Nil};
+
+use super::{MapFn, Mapper};
+
+/// Transform heterogenous list into homogenous heterogenous list.
+pub trait MapUniform<R, Mapper>: HList {
+    /// Type of new homogenous heterogenous list after transformation.
+    type Output: HList;
+
+    /// Transforms the heterogenous list into homogenous heterogenous list
+    /// by applying an operation to each element by mapper,
+    /// where each element is transformed into a value of the same type.
+    ///
+    /// This is the same as [`Map::map()`](crate::ops::Map::map),
+    /// but the resulting list can be iterated over because all of its elements have the same type.
+    ///
+    /// # Examples
+    ///
+    /// You can map the list if it is homogenous (all elements have the same type):
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapUniform};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let list = list.map_uniform(|x| x > 1);
+    /// assert_eq!(list, hlist![false, true, true]);
+    /// ```
+    ///
+    /// Mapping of heterogenous list is possible with heterogenous list of closures as mapper:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapUniform};
+    ///
+    /// let list = hlist![1, 2.0, "3"];
+    /// let list = list.map_uniform(
+    ///     hlist![
+    ///         |i: i32| i as f32,
+    ///         |f: f64| f as f32,
+    ///         |s: &str| s.parse::<f32>().unwrap(),
+    ///     ]
+    /// );
+    /// assert_eq!(list.into_iter().sum::<f32>(), 6.0);
+    /// ```
+    ///
+    /// Or with special implementation of [mapper function](MapFn):
+    ///
+    /// ```
+    /// use core::fmt::Debug;
+    ///
+    /// use hlist2::{
+    ///     hlist,
+    ///     ops::{MapFn, MapUniform, Mapper},
+    /// };
+    ///
+    /// struct ToDebugString;
+    ///
+    /// impl<T> MapFn<T> for ToDebugString
+    /// where
+    ///     T: Debug,
+    /// {
+    ///     type Output = String;
+    ///     fn map(&mut self, value: T) -> String { format!("{value:?}") }
+    /// }
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, "3"];
+    /// let list = list.map_uniform(Mapper(ToDebugString));
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), ["1", "2.0", "\"3\""]);
+    /// ```
+    fn map_uniform(self, mapper: Mapper) -> Self::Output;
+}
+
+impl<R, M> MapUniform<R, M> for Nil {
+    type Output = Nil;
+
+    fn map_uniform(self, _: M) -> Self::Output {
+        self
+    }
+}
+
+impl<M, R, Head, Tail> MapUniform<R, M> for Cons<Head, Tail>
+where
+    M: FnMut(Head) -> R,
+    Tail: MapUniform<R, M>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_uniform(self, mut mapper: M) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = mapper(head);
+        let tail = tail.map_uniform(mapper);
+        Cons(head, tail)
+    }
+}
+
+impl<MHead, MTail, Head, Tail, R> MapUniform<R, Cons<MHead, MTail>> for Cons<Head, Tail>
+where
+    MHead: FnOnce(Head) -> R,
+    Tail: MapUniform<R, MTail>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_uniform(self, mapper: Cons<MHead, MTail>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let Cons(mapper_head, mapper_tail) = mapper;
+        let head = mapper_head(head);
+        let tail = tail.map_uniform(mapper_tail);
+        Cons(head, tail)
+    }
+}
+
+impl<M, R, Head, Tail> MapUniform<R, Mapper<M>> for Cons<Head, Tail>
+where
+    M: MapFn<Head, Output = R>,
+    Tail: MapUniform<R, Mapper<M>>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_uniform(self, mut mapper: Mapper<M>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = mapper.map(head);
+        let tail = tail.map_uniform(mapper);
+        Cons(head, tail)
+    }
+}
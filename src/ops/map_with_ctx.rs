@@ -0,0 +1,92 @@
+use crate::{Cons, HList, Nil};
+
+/// Transform one heterogenous list into another with shared context.
+pub trait MapWithCtx<Ctx, Mapper>: HList {
+    /// Type of new heterogenous list after transformation.
+    type Output: HList;
+
+    /// Transforms the heterogenous list into another heterogenous list
+    /// by applying an operation to each element by mapper.
+    ///
+    /// Shared context is passed by immutable reference into the mapper
+    /// along with each element of the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// You can map the list if it is homogenous (all elements have the same type):
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapWithCtx};
+    ///
+    /// struct Config {
+    ///     scale: i32,
+    /// }
+    ///
+    /// let config = Config { scale: 10 };
+    /// let list = hlist![1, 2, 3];
+    /// let list = list.map_with_ctx(&config, |config: &Config, x| x * config.scale);
+    /// assert_eq!(list, hlist![10, 20, 30]);
+    /// ```
+    ///
+    /// Mapping of heterogenous list is possible with heterogenous list of closures as mapper:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MapWithCtx};
+    ///
+    /// struct Config {
+    ///     prefix: &'static str,
+    ///     scale: f64,
+    /// }
+    ///
+    /// let config = Config { prefix: "item", scale: 0.5 };
+    /// let list = hlist![1, 2.0];
+    /// let list = list.map_with_ctx(
+    ///     &config,
+    ///     hlist![
+    ///         |config: &Config, x: i32| format!("{}-{x}", config.prefix),
+    ///         |config: &Config, y: f64| y * config.scale,
+    ///     ],
+    /// );
+    /// assert_eq!(list, hlist![String::from("item-1"), 1.0]);
+    /// ```
+    fn map_with_ctx(self, ctx: &Ctx, mapper: Mapper) -> Self::Output;
+}
+
+impl<C, M> MapWithCtx<C, M> for Nil {
+    type Output = Nil;
+
+    fn map_with_ctx(self, _: &C, _: M) -> Self::Output {
+        self
+    }
+}
+
+impl<C, M, R, Head, Tail> MapWithCtx<C, M> for Cons<Head, Tail>
+where
+    M: FnMut(&C, Head) -> R,
+    Tail: MapWithCtx<C, M>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_with_ctx(self, ctx: &C, mut mapper: M) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = mapper(ctx, head);
+        let tail = tail.map_with_ctx(ctx, mapper);
+        Cons(head, tail)
+    }
+}
+
+impl<C, MHead, MTail, R, Head, Tail> MapWithCtx<C, Cons<MHead, MTail>> for Cons<Head, Tail>
+where
+    MHead: FnOnce(&C, Head) -> R,
+    Tail: MapWithCtx<C, MTail>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn map_with_ctx(self, ctx: &C, mapper: Cons<MHead, MTail>) -> Self::Output {
+        let Cons(head, tail) = self;
+        let Cons(mapper_head, mapper_tail) = mapper;
+        let head = mapper_head(ctx, head);
+        let tail = tail.map_with_ctx(ctx, mapper_tail);
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Cons, HList, Nil};
+
+/// Mask elements of the heterogenous list by a list of booleans.
+pub trait Mask<M>: HList
+where
+    M: HList,
+{
+    /// Replaces elements of the heterogenous list whose mask is `false`
+    /// with [default](Default) values of their types.
+    ///
+    /// Length of the mask must be equal to the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Mask};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// assert_eq!(list.mask(hlist![true, false, true]), hlist![1, 0, 3]);
+    /// assert_eq!(list.mask(hlist![true, true, true]), hlist![1, 2, 3]);
+    /// assert_eq!(list.mask(hlist![false, false, false]), hlist![0, 0, 0]);
+    ///
+    /// let list = hlist![1, String::from("hello"), Some(true)];
+    /// let masked = list.mask(hlist![false, true, false]);
+    /// assert_eq!(masked, hlist![0, String::from("hello"), None]);
+    /// ```
+    ///
+    /// Length of the mask must be equal to the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::Mask};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let masked = list.mask(hlist![true, false]);
+    /// ```
+    fn mask(self, mask: M) -> Self;
+}
+
+impl Mask<Nil> for Nil {
+    fn mask(self, _: Nil) -> Self {
+        self
+    }
+}
+
+impl<Head, Tail, MTail> Mask<Cons<bool, MTail>> for Cons<Head, Tail>
+where
+    Head: Default,
+    Tail: Mask<MTail>,
+    MTail: HList,
+{
+    fn mask(self, mask: Cons<bool, MTail>) -> Self {
+        let Cons(head, tail) = self;
+        let Cons(keep, mask_tail) = mask;
+
+        let head = if keep { head } else { Head::default() };
+        let tail = tail.mask(mask_tail);
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::HList;
+
+use super::Append;
+
+/// Append new element to the heterogenous list only if constant condition holds.
+///
+/// If condition is `true`, new element is appended to the end of the list,
+/// as if [`Append`] trait was used. Otherwise, the list is left unchanged.
+pub trait MaybeAppend<const COND: bool>: HList {
+    /// Type of heterogenous list with new element if condition holds,
+    /// or the type of the list itself otherwise.
+    type Output<T>: HList;
+
+    /// Appends new element to the heterogenous list if condition holds.
+    ///
+    /// If condition does not hold, new element is dropped and the list is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::MaybeAppend};
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let appended = MaybeAppend::<true>::maybe_append(list, true);
+    /// assert_eq!(appended, hlist![1, 2.0, true]);
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let not_appended = MaybeAppend::<false>::maybe_append(list, true);
+    /// assert_eq!(not_appended, hlist![1, 2.0]);
+    /// ```
+    fn maybe_append<T>(self, value: T) -> Self::Output<T>;
+}
+
+impl<L> MaybeAppend<true> for L
+where
+    L: Append,
+{
+    type Output<T> = L::Output<T>;
+
+    fn maybe_append<T>(self, value: T) -> Self::Output<T> {
+        self.append(value)
+    }
+}
+
+impl<L> MaybeAppend<false> for L
+where
+    L: HList,
+{
+    type Output<T> = L;
+
+    fn maybe_append<T>(self, _: T) -> Self::Output<T> {
+        self
+    }
+}
+
+/// Append new element to the heterogenous list only if constant condition holds.
+pub trait AppendIf: HList {
+    /// Appends new element to the heterogenous list if condition holds.
+    ///
+    /// Type of the resulting list depends on the condition, see [`MaybeAppend`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::AppendIf, Cons, Nil};
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let appended: Cons<i32, Cons<f64, Cons<bool, Nil>>> = list.append_if::<true, _>(true);
+    /// assert_eq!(appended, hlist![1, 2.0, true]);
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let not_appended: Cons<i32, Cons<f64, Nil>> = list.append_if::<false, _>(true);
+    /// assert_eq!(not_appended, hlist![1, 2.0]);
+    /// ```
+    ///
+    /// This can be used to add elements depending on some constant parameter:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{AppendIf, MaybeAppend}, HList};
+    ///
+    /// const DEBUG: bool = cfg!(debug_assertions);
+    ///
+    /// fn with_debug_info<L>(list: L) -> <L as MaybeAppend<DEBUG>>::Output<&'static str>
+    /// where
+    ///     L: MaybeAppend<DEBUG>,
+    /// {
+    ///     list.append_if::<DEBUG, _>("debug info")
+    /// }
+    ///
+    /// let list = with_debug_info(hlist![1, 2.0]);
+    /// assert_eq!(list.len(), if DEBUG { 3 } else { 2 });
+    /// ```
+    fn append_if<const COND: bool, T>(self, value: T) -> <Self as MaybeAppend<COND>>::Output<T>
+    where
+        Self: MaybeAppend<COND>;
+}
+
+impl<L> AppendIf for L
+where
+    L: HList,
+{
+    fn append_if<const COND: bool, T>(self, value: T) -> <Self as MaybeAppend<COND>>::Output<T>
+    where
+        Self: MaybeAppend<COND>,
+    {
+        self.maybe_append(value)
+    }
+}
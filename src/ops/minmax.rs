@@ -0,0 +1,51 @@
+use crate::HList;
+
+use super::zip_each::ZipEach;
+
+/// Compute element-wise minimum and maximum of two homogenous lists.
+pub trait MinMaxEach<T>: HList
+where
+    T: Ord,
+{
+    /// Returns new homogenous list of element-wise minima of two homogenous lists.
+    ///
+    /// Type of the list is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::MinMaxEach};
+    ///
+    /// let list: HList![i32, i32, i32] = hlist![1, 5, 3].min_each(hlist![4, 2, 3]);
+    /// assert_eq!(list, hlist![1, 2, 3]);
+    /// ```
+    fn min_each(self, other: Self) -> Self;
+
+    /// Returns new homogenous list of element-wise maxima of two homogenous lists.
+    ///
+    /// Type of the list is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::MinMaxEach};
+    ///
+    /// let list: HList![i32, i32, i32] = hlist![1, 5, 3].max_each(hlist![4, 2, 3]);
+    /// assert_eq!(list, hlist![4, 5, 3]);
+    /// ```
+    fn max_each(self, other: Self) -> Self;
+}
+
+impl<T, L> MinMaxEach<T> for L
+where
+    T: Ord,
+    L: ZipEach<T>,
+{
+    fn min_each(self, other: Self) -> Self {
+        self.zip_with(other, Ord::min)
+    }
+
+    fn max_each(self, other: Self) -> Self {
+        self.zip_with(other, Ord::max)
+    }
+}
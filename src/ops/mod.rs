@@ -2,40 +2,229 @@
 
 pub use self::{
     append::Append,
+    argextreme::ArgExtreme,
+    as_slices::AsSlices,
+    bool_reduce::BoolReduce,
+    borrow_except::BorrowExcept,
+    borrowable::Borrowable,
+    chunk_by::ChunkBy,
+    combine::{Combine, Semigroup},
+    compose::Compose,
+    consume_each::{ConsumeEach, HListCallback},
+    contains_subseq::ContainsSubsequence,
+    convert_into::ConvertInto,
+    count_some::CountSome,
+    defaults::Defaults,
+    distance::DistanceBetween,
+    dot::Dot,
+    drop_types::DropTypes,
+    enumerate::EnumerateFromEnd,
+    eq_each::EqEach,
+    eq_ignoring::EqIgnoring,
     extend::Extend,
+    extract_unique::ExtractUnique,
     flatten::Flatten,
-    fold::{Fold, FoldFn, Folder, RFold},
+    fold::{Fold, FoldEnds, FoldFn, FoldIndexed, FoldWithRest, Folder, RFold},
+    fold2::Fold2,
+    for_each_debug::ForEachDebug,
+    from_index::FromIndexFn,
+    from_range::FromRange,
     get::Get,
+    get_cloned::GetCloned,
     get_many::GetMany,
+    get_two::GetTwo,
+    grid::Grid,
+    hash::HashValue,
     index::{Dec, Here, Inc, Index, ManyIndex, There},
+    intersperse::InterspersedWith,
+    iterate::Iterate,
+    len_cmp::LenCmp,
+    len_eq::LenEq,
     map::{Map, MapFn, Mapper},
+    map_head_tail::MapHeadTail,
+    map_if::MapIf,
+    map_indexed::MapIndexed,
+    map_uniform::MapUniform,
+    map_with_ctx::MapWithCtx,
+    mask::Mask,
+    maybe_append::{AppendIf, MaybeAppend},
+    minmax::MinMaxEach,
+    multiset::SameMultisetAs,
+    not_contains::NotContains,
+    pad::PadTo,
+    partition_index::PartitionByIndex,
+    permutation::Permutation,
+    phantom::PhantomTuple,
     pop::Pop,
     pop_front::PopFront,
+    position::Position,
     prepend::Prepend,
+    reborrow::ReborrowMut,
     remove::Remove,
     remove_many::RemoveMany,
-    reverse::Reverse,
+    reverse::{DeepReverse, Reverse, ReverseRange},
+    rotate::{RotateLeft, RotateRuntime},
+    rotate_to::RotateToType,
+    running::{RunningMax, RunningMin},
+    same_len::SameLenAs,
+    scan_concat::ScanConcat,
+    scanl::ScanL,
+    shift_rows::ShiftRows,
     shuffle::Shuffle,
+    slice::Slice,
+    splice::{Splice, SpliceAt},
+    split_at::SplitAt,
+    split_ends::SplitEnds,
+    sublist_lengths::SublistLengths,
+    tail_of::TailOf,
     to_ref::ToRef,
+    try_map_ref::TryMapRef,
+    unfold::Unfold,
+    unique::Unique,
     unzip::Unzip,
-    zip::Zip,
+    zip::{Zip, ZipRef, ZipTuple},
+    zip_fold::{Zip3Fold, ZipFold},
+    zip_indices::ZipIndices,
 };
 
+#[cfg(feature = "alloc")]
+pub use self::{
+    bind_each::BindEach, collect_into::CollectInto, debug_rows::ToDebugRows,
+    dedup::DedupConsecutive, filter_map::FilterMap, flatten_options::FlattenOptions,
+    indexed_map::IntoIndexedMap, interleave::InterleaveHomogenous, into_boxed::IntoBoxedSlice,
+    into_boxed_dyn::IntoBoxedDyn, into_pairs::IntoPairs, leave_one_out::LeaveOneOut,
+    map_fold::MapFold, sort::IntoSorted, split_results::SplitResults,
+    stable_partition::StablePartition, type_signature::TypeSignature, validate::Validate,
+    window_fold::WindowFold,
+};
+
+#[cfg(feature = "heapless")]
+pub use self::into_heapless::IntoHeapless;
+
 mod append;
+mod argextreme;
+mod as_slices;
+mod bool_reduce;
+mod borrow_except;
+mod borrowable;
+mod chunk_by;
+mod combine;
+mod compose;
+mod consume_each;
+mod contains_subseq;
+mod convert_into;
+mod count_some;
+mod defaults;
+mod distance;
+mod dot;
+mod drop_types;
+mod enumerate;
+mod eq_each;
+mod eq_ignoring;
 mod extend;
+mod extract_unique;
 mod flatten;
 mod fold;
+mod fold2;
+mod for_each_debug;
+mod from_index;
+mod from_range;
 mod get;
+mod get_cloned;
 mod get_many;
+mod get_two;
+mod grid;
+mod hash;
 mod index;
+mod intersperse;
+mod iterate;
+mod len_cmp;
+mod len_eq;
 mod map;
+mod map_head_tail;
+mod map_if;
+mod map_indexed;
+mod map_uniform;
+mod map_with_ctx;
+mod mask;
+mod maybe_append;
+mod minmax;
+mod multiset;
+mod not_contains;
+mod pad;
+mod partition_index;
+mod permutation;
+mod phantom;
 mod pop;
 mod pop_front;
+mod position;
 mod prepend;
+mod reborrow;
 mod remove;
 mod remove_many;
 mod reverse;
+mod rotate;
+mod rotate_to;
+mod running;
+mod same_len;
+mod scan_concat;
+mod scanl;
+mod shift_rows;
 mod shuffle;
+mod slice;
+mod splice;
+mod split_at;
+mod split_ends;
+mod sublist_lengths;
+mod tail_of;
 mod to_ref;
+mod try_map_ref;
+mod unfold;
+mod unique;
 mod unzip;
 mod zip;
+mod zip_each;
+mod zip_fold;
+mod zip_indices;
+
+#[cfg(feature = "alloc")]
+mod bind_each;
+#[cfg(feature = "alloc")]
+mod collect_into;
+#[cfg(feature = "alloc")]
+mod debug_rows;
+#[cfg(feature = "alloc")]
+mod dedup;
+#[cfg(feature = "alloc")]
+mod filter_map;
+#[cfg(feature = "alloc")]
+mod flatten_options;
+#[cfg(feature = "alloc")]
+mod indexed_map;
+#[cfg(feature = "alloc")]
+mod interleave;
+#[cfg(feature = "alloc")]
+mod into_boxed;
+#[cfg(feature = "alloc")]
+mod into_boxed_dyn;
+#[cfg(feature = "alloc")]
+mod into_pairs;
+#[cfg(feature = "alloc")]
+mod leave_one_out;
+#[cfg(feature = "alloc")]
+mod map_fold;
+#[cfg(feature = "alloc")]
+mod sort;
+#[cfg(feature = "alloc")]
+mod split_results;
+#[cfg(feature = "alloc")]
+mod stable_partition;
+#[cfg(feature = "alloc")]
+mod type_signature;
+#[cfg(feature = "alloc")]
+mod validate;
+#[cfg(feature = "alloc")]
+mod window_fold;
+
+#[cfg(feature = "heapless")]
+mod into_heapless;
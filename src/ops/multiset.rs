@@ -0,0 +1,88 @@
+use crate::HList;
+
+use super::{ManyIndex, Shuffle};
+
+/// Heterogenous list which contains the same types as another heterogenous list,
+/// regardless of their order.
+///
+/// This trait is implemented for all heterogenous lists which can be [shuffled](Shuffle)
+/// into another heterogenous list, so it can be used to bound generic types.
+///
+/// Index generic parameter is used to determine a way to reorder the list,
+/// because there may be more than one if type of elements is not unique.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{HList, ops::{ManyIndex, SameMultisetAs}};
+///
+/// fn assert_same_multiset<L, Other, I>()
+/// where
+///     L: SameMultisetAs<Other, I>,
+///     Other: HList,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// assert_same_multiset::<HList![i32, bool], HList![bool, i32], _>();
+/// assert_same_multiset::<HList![bool, i32], HList![i32, bool], _>();
+/// ```
+///
+/// Lists with different types, or with different count of the same type, are not the same:
+///
+/// ```compile_fail
+/// use hlist2::{HList, ops::{ManyIndex, SameMultisetAs}};
+///
+/// fn assert_same_multiset<L, Other, I>()
+/// where
+///     L: SameMultisetAs<Other, I>,
+///     Other: HList,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// assert_same_multiset::<HList![i32, bool], HList![bool, f64], _>();
+/// ```
+///
+/// ```compile_fail
+/// use hlist2::{HList, ops::{ManyIndex, SameMultisetAs}};
+///
+/// fn assert_same_multiset<L, Other, I>()
+/// where
+///     L: SameMultisetAs<Other, I>,
+///     Other: HList,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// assert_same_multiset::<HList![i32, bool, i32], HList![bool, i32], _>();
+/// ```
+pub trait SameMultisetAs<Other, I>: Shuffle<Other, I>
+where
+    Other: HList,
+    I: ManyIndex,
+{
+    /// Reorders elements of the heterogenous list like in another heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::SameMultisetAs};
+    ///
+    /// let list = hlist![1, true, "hello world"];
+    /// let reordered: HList![&str, i32, bool] = list.reorder_like();
+    /// assert_eq!(reordered, hlist!["hello world", 1, true]);
+    /// ```
+    fn reorder_like(self) -> Other;
+}
+
+impl<T, Other, I> SameMultisetAs<Other, I> for T
+where
+    T: Shuffle<Other, I>,
+    Other: HList,
+    I: ManyIndex,
+{
+    fn reorder_like(self) -> Other {
+        self.shuffle()
+    }
+}
@@ -0,0 +1,62 @@
+use core::marker::PhantomData;
+
+use crate::HList;
+
+use super::{Get, Index};
+
+/// Heterogenous list which does not contain an element of provided type.
+///
+/// Rust cannot check whether two types are not the same, so this trait relies on type inference:
+/// it is implemented for any list with one witness, and additionally for lists
+/// which contain an element of provided type with another witness
+/// (which holds the [index](Index) of this element).
+/// So the witness can be inferred by the compiler only if the list does not contain such element,
+/// otherwise it is ambiguous and compilation fails.
+///
+/// Note that this check only holds where the witness is inferred,
+/// just like for [`Unique`](super::Unique) trait.
+/// Both witnesses cannot be named outside of this crate,
+/// so the witness generic parameter can only be inferred by the compiler.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{HList, ops::NotContains};
+///
+/// fn assert_not_contains<L, T, W>()
+/// where
+///     L: NotContains<T, W>,
+/// {
+/// }
+///
+/// assert_not_contains::<HList![], i32, _>();
+/// assert_not_contains::<HList![f64, bool, &str], i32, _>();
+/// ```
+///
+/// List which contains an element of provided type does not satisfy such bound:
+///
+/// ```compile_fail
+/// use hlist2::{HList, ops::NotContains};
+///
+/// fn assert_not_contains<L, T, W>()
+/// where
+///     L: NotContains<T, W>,
+/// {
+/// }
+///
+/// assert_not_contains::<HList![f64, i32, &str], i32, _>();
+/// ```
+pub trait NotContains<T, W>: HList {}
+
+impl<T, L> NotContains<T, Absent> for L where L: HList {}
+
+impl<T, L, I> NotContains<T, Present<I>> for L
+where
+    L: Get<T, I>,
+    I: Index,
+{
+}
+
+pub struct Absent;
+
+pub struct Present<I>(PhantomData<I>);
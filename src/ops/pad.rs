@@ -0,0 +1,62 @@
+use crate::{iter::Homogenous, Len};
+
+use super::Unfold;
+
+/// Pad the homogenous list to the target length with a fill value.
+pub trait PadTo<Output>: Homogenous + Len
+where
+    Output: Unfold<Self::Item> + Len,
+{
+    /// Appends clones of the fill value to the end of the homogenous list
+    /// until its length is equal to `N`.
+    ///
+    /// Target length must be equal to the length of the output list,
+    /// and cannot be less than the length of the source list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::PadTo};
+    ///
+    /// let list = hlist![1, 2];
+    /// let padded: HList![i32, i32, i32, i32] = list.pad_to::<4>(0);
+    /// assert_eq!(padded, hlist![1, 2, 0, 0]);
+    ///
+    /// let padded: HList![i32, i32] = list.pad_to::<2>(0);
+    /// assert_eq!(padded, list);
+    /// ```
+    ///
+    /// List cannot be padded to the length less than its own:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, HList, ops::PadTo};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let padded: HList![i32, i32] = list.pad_to::<2>(0);
+    /// ```
+    fn pad_to<const N: usize>(self, fill: Self::Item) -> Output
+    where
+        Self::Item: Clone;
+}
+
+impl<T, Output> PadTo<Output> for T
+where
+    T: Homogenous + Len,
+    Output: Unfold<T::Item> + Len,
+{
+    fn pad_to<const N: usize>(self, fill: Self::Item) -> Output
+    where
+        Self::Item: Clone,
+    {
+        const {
+            assert!(
+                T::LEN <= N && N == Output::LEN,
+                "target length must be equal to the output length and not less than the list length"
+            )
+        };
+
+        Output::unfold(self.into_iter(), |iter| {
+            iter.next().unwrap_or_else(|| fill.clone())
+        })
+    }
+}
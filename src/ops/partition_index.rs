@@ -0,0 +1,49 @@
+use crate::HList;
+
+use super::{ManyIndex, RemoveMany};
+
+/// Partition the heterogenous list into selected elements and the rest of them.
+pub trait PartitionByIndex: HList {
+    /// Partitions the heterogenous list into selected elements and the rest of them.
+    ///
+    /// Elements are selected by their types, so this is the same as [`RemoveMany::remove_many()`],
+    /// but types of selected elements can be provided directly to the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::PartitionByIndex, HList};
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    /// let (selected, rest) = list.partition_by_index::<HList![bool], _>();
+    /// assert_eq!(selected, hlist![true]);
+    /// assert_eq!(rest, hlist![1, 2.0]);
+    ///
+    /// let (selected, rest) = list.partition_by_index::<HList![f64, i32], _>();
+    /// assert_eq!(selected, hlist![2.0, 1]);
+    /// assert_eq!(rest, hlist![true]);
+    /// ```
+    fn partition_by_index<Selected, I>(
+        self,
+    ) -> (Selected, <Self as RemoveMany<Selected, I>>::Remainder)
+    where
+        Self: RemoveMany<Selected, I>,
+        Selected: HList,
+        I: ManyIndex;
+}
+
+impl<L> PartitionByIndex for L
+where
+    L: HList,
+{
+    fn partition_by_index<Selected, I>(
+        self,
+    ) -> (Selected, <Self as RemoveMany<Selected, I>>::Remainder)
+    where
+        Self: RemoveMany<Selected, I>,
+        Selected: HList,
+        I: ManyIndex,
+    {
+        self.remove_many()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::HList;
+
+use super::{ManyIndex, Shuffle};
+
+/// Reinterpret the heterogenous list as a permutation of another heterogenous list.
+///
+/// This trait is implemented for all heterogenous lists which can be [shuffled](Shuffle)
+/// into another heterogenous list. Unlike [`Shuffle`], it exposes the index list
+/// which was used to reorder elements, so it can be inspected
+/// when there is more than one way to reorder the list because of repeated types.
+pub trait Permutation<Target, I>: Shuffle<Target, I>
+where
+    Target: HList,
+    I: ManyIndex,
+{
+    /// Index list which was used to reorder elements of the heterogenous list.
+    ///
+    /// Each index of this list points to the element which is moved out
+    /// of the remaining part of the heterogenous list on each step of reordering.
+    type PermutationWitness: ManyIndex;
+
+    /// Reinterprets the heterogenous list as a permutation of another heterogenous list.
+    ///
+    /// This is the same as [`Shuffle::shuffle()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Permutation, HList};
+    ///
+    /// let list = hlist![1_i32, 2.0_f64];
+    /// let permutation = Permutation::<HList![f64, i32], _>::as_permutation_of(list);
+    /// assert_eq!(permutation, hlist![2.0, 1]);
+    /// ```
+    ///
+    /// Index list used to reorder elements can be accessed in generic code:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, ManyIndex, Permutation, There}, HList};
+    ///
+    /// fn witness<Target, L, I>(_: &L) -> L::PermutationWitness
+    /// where
+    ///     L: Permutation<Target, I>,
+    ///     Target: HList,
+    ///     I: ManyIndex,
+    /// {
+    ///     Default::default()
+    /// }
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    /// let witness: HList![There<There<Here>>, Here, Here] =
+    ///     witness::<HList![bool, i32, f64], _, _>(&list);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_permutation_of(self) -> Target;
+}
+
+impl<T, Target, I> Permutation<Target, I> for T
+where
+    T: Shuffle<Target, I>,
+    Target: HList,
+    I: ManyIndex,
+{
+    type PermutationWitness = I;
+
+    fn as_permutation_of(self) -> Target {
+        self.shuffle()
+    }
+}
@@ -0,0 +1,57 @@
+use core::marker::PhantomData;
+
+use crate::HList;
+
+/// Represent types of elements of the heterogenous list as a tuple of [`PhantomData`].
+pub trait PhantomTuple: HList {
+    /// Tuple of [`PhantomData`] for each element type of the heterogenous list.
+    type Phantom;
+
+    /// Returns a tuple of [`PhantomData`] for each element type of the heterogenous list.
+    ///
+    /// Order of types in the tuple is the same as in the heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::marker::PhantomData;
+    ///
+    /// use hlist2::{ops::PhantomTuple, HList};
+    ///
+    /// let phantom: (PhantomData<i32>, PhantomData<f64>, PhantomData<bool>) =
+    ///     <HList![i32, f64, bool]>::phantom_tuple();
+    /// assert_eq!(phantom, (PhantomData, PhantomData, PhantomData));
+    ///
+    /// let phantom: () = <HList![]>::phantom_tuple();
+    /// assert_eq!(phantom, ());
+    /// ```
+    fn phantom_tuple() -> Self::Phantom;
+}
+
+macro_rules! phantom_tuple {
+    ($($types:ident),*) => {
+        impl<$($types),*> PhantomTuple for $crate::HList!($($types,)*) {
+            type Phantom = ($(PhantomData<$types>,)*);
+
+            #[allow(clippy::unused_unit)]
+            fn phantom_tuple() -> Self::Phantom {
+                ($(PhantomData::<$types>,)*)
+            }
+        }
+    };
+}
+
+// Phantom tuple is implemented for heterogenous lists of size 12 and less
+phantom_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+phantom_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+phantom_tuple!(A, B, C, D, E, F, G, H, I, J);
+phantom_tuple!(A, B, C, D, E, F, G, H, I);
+phantom_tuple!(A, B, C, D, E, F, G, H);
+phantom_tuple!(A, B, C, D, E, F, G);
+phantom_tuple!(A, B, C, D, E, F);
+phantom_tuple!(A, B, C, D, E);
+phantom_tuple!(A, B, C, D);
+phantom_tuple!(A, B, C);
+phantom_tuple!(A, B);
+phantom_tuple!(A);
+phantom_tuple!();
@@ -0,0 +1,40 @@
+use crate::{Cons, HList};
+
+use super::{Get, Here, Index, There};
+
+/// Position of the element of the heterogenous list by type.
+pub trait Position<T, I>: Get<T, I>
+where
+    I: Index,
+{
+    /// Position of the element of the heterogenous list by type,
+    /// or count of elements before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{HList, ops::Position};
+    ///
+    /// type List = HList![i32, f64, bool];
+    /// assert_eq!(<List as Position<i32, _>>::POSITION, 0);
+    /// assert_eq!(<List as Position<bool, _>>::POSITION, 2);
+    /// ```
+    const POSITION: usize;
+}
+
+/// Desired type is located in the head of the heterogenous list.
+impl<Head, Tail> Position<Head, Here> for Cons<Head, Tail>
+where
+    Tail: HList + ?Sized,
+{
+    const POSITION: usize = 0;
+}
+
+/// Desired type is located somewhere in the tail of the heterogenous list.
+impl<Head, Tail, FromTail, TailIndex> Position<FromTail, There<TailIndex>> for Cons<Head, Tail>
+where
+    Tail: Position<FromTail, TailIndex> + ?Sized,
+    TailIndex: Index,
+{
+    const POSITION: usize = 1 + Tail::POSITION;
+}
@@ -0,0 +1,66 @@
+use crate::{Cons, HList, Nil};
+
+/// Reborrow heterogenous list of mutable references.
+pub trait ReborrowMut: HList {
+    /// Type of new heterogenous list of shorter-lived mutable references.
+    type Output<'a>: HList
+    where
+        Self: 'a;
+
+    /// Reborrows each mutable reference of the heterogenous list,
+    /// producing new heterogenous list of shorter-lived mutable references.
+    ///
+    /// This allows to pass the list into a function by value
+    /// without consuming the original list of mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ReborrowMut, HList};
+    ///
+    /// fn increment(list: HList![&mut i32, &mut f64]) {
+    ///     let hlist![a, b] = list;
+    ///     *a += 1;
+    ///     *b += 1.0;
+    /// }
+    ///
+    /// let (mut a, mut b) = (1, 2.0);
+    /// let mut refs = hlist![&mut a, &mut b];
+    /// increment(refs.reborrow_mut());
+    /// increment(refs.reborrow_mut());
+    ///
+    /// let hlist![a_ref, b_ref] = refs;
+    /// *a_ref *= 10;
+    /// *b_ref *= 10.0;
+    /// assert_eq!((a, b), (30, 40.0));
+    /// ```
+    fn reborrow_mut(&mut self) -> Self::Output<'_>;
+}
+
+impl ReborrowMut for Nil {
+    type Output<'a>
+        = Nil
+    where
+        Self: 'a;
+
+    fn reborrow_mut(&mut self) -> Self::Output<'_> {
+        *self
+    }
+}
+
+impl<Head, Tail> ReborrowMut for Cons<&mut Head, Tail>
+where
+    Head: ?Sized,
+    Tail: ReborrowMut,
+{
+    type Output<'a>
+        = Cons<&'a mut Head, Tail::Output<'a>>
+    where
+        Self: 'a;
+
+    fn reborrow_mut(&mut self) -> Self::Output<'_> {
+        let Cons(head, tail) = self;
+        let tail = tail.reborrow_mut();
+        Cons(head, tail)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{Cons, HList, Nil};
 
+use super::{Extend, Index, Map, MapFn, Mapper, SplitAt};
+
 /// Reverse elements of the heterogenous list.
 pub trait Reverse: HList {
     /// Type of new heterogenous list with the opposite order of elements.
@@ -64,3 +66,104 @@ where
         tail.rewind(Cons(next, done))
     }
 }
+
+/// Reverse elements of the heterogenous list in a sub-range.
+pub trait ReverseRange<Start, End>: HList
+where
+    Start: Index,
+    End: Index,
+{
+    /// Type of new heterogenous list with the opposite order of elements in the sub-range.
+    type Output: HList;
+
+    /// Reverses elements of the heterogenous list in the half-open range `[Start, End)`.
+    ///
+    /// Elements outside of the range are left in place.
+    /// Start index cannot be greater than the end index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, ReverseRange, There}};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let reversed = ReverseRange::<There<Here>, There<There<There<Here>>>>::reverse_range(list);
+    /// assert_eq!(reversed, hlist![1, 3, 2, 4]);
+    ///
+    /// let list = hlist![1, 2.0, true, "hello world"];
+    /// let reversed = ReverseRange::<Here, There<There<There<Here>>>>::reverse_range(list);
+    /// assert_eq!(reversed, hlist![true, 2.0, 1, "hello world"]);
+    /// ```
+    fn reverse_range(self) -> Self::Output;
+}
+
+impl<T, Start, End> ReverseRange<Start, End> for T
+where
+    T: SplitAt<End>,
+    T::Prefix: SplitAt<Start>,
+    <T::Prefix as SplitAt<Start>>::Prefix: Extend,
+    <T::Prefix as SplitAt<Start>>::Suffix: Reverse,
+    <<T::Prefix as SplitAt<Start>>::Suffix as Reverse>::Output: Extend,
+    Start: Index,
+    End: Index,
+{
+    type Output = <<T::Prefix as SplitAt<Start>>::Prefix as Extend>::Output<
+        <<<T::Prefix as SplitAt<Start>>::Suffix as Reverse>::Output as Extend>::Output<T::Suffix>,
+    >;
+
+    fn reverse_range(self) -> Self::Output {
+        let (front, back) = self.split_at();
+        let (front, range) = front.split_at();
+        let range = range.reverse();
+        front.extend(range.extend(back))
+    }
+}
+
+/// Reverse elements of the heterogenous list of heterogenous lists
+/// together with elements of each inner heterogenous list.
+pub trait DeepReverse: HList {
+    /// Type of new heterogenous list with the opposite order of elements on both levels.
+    type Output: HList;
+
+    /// Reverses elements of the heterogenous list and elements of each inner heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::DeepReverse};
+    ///
+    /// let list = hlist![hlist![1, 2], hlist![3, 4]];
+    /// assert_eq!(list.deep_reverse(), hlist![hlist![4, 3], hlist![2, 1]]);
+    ///
+    /// let list = hlist![hlist![1, 2.0, true], hlist![], hlist!["hello world"]];
+    /// let reversed = list.deep_reverse();
+    /// assert_eq!(reversed, hlist![hlist!["hello world"], hlist![], hlist![true, 2.0, 1]]);
+    /// ```
+    fn deep_reverse(self) -> Self::Output;
+}
+
+impl<T> DeepReverse for T
+where
+    T: Reverse,
+    T::Output: Map<Mapper<ReverseEach>>,
+{
+    type Output = <T::Output as Map<Mapper<ReverseEach>>>::Output;
+
+    fn deep_reverse(self) -> Self::Output {
+        let list = self.reverse();
+        list.map(Mapper(ReverseEach))
+    }
+}
+
+pub struct ReverseEach;
+
+impl<T> MapFn<T> for ReverseEach
+where
+    T: Reverse,
+{
+    type Output = T::Output;
+
+    fn map(&mut self, list: T) -> Self::Output {
+        list.reverse()
+    }
+}
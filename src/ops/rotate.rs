@@ -0,0 +1,148 @@
+use core::mem::swap;
+
+use crate::{Cons, HList, Nil};
+
+use super::{Extend, Index, SplitAt};
+
+/// Rotate elements of the heterogenous list to the left.
+pub trait RotateLeft<I>: HList
+where
+    I: Index,
+{
+    /// Type of new heterogenous list with rotated elements.
+    type Output: HList;
+
+    /// Rotates elements of the heterogenous list to the left by an index.
+    ///
+    /// After this operation, the element at provided index will become the first one,
+    /// and all the elements before provided index will be moved to the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, RotateLeft, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "hello world"];
+    /// let rotated = RotateLeft::<There<Here>>::rotate_left(list);
+    /// assert_eq!(rotated, hlist![2.0, true, "hello world", 1]);
+    /// ```
+    fn rotate_left(self) -> Self::Output;
+}
+
+impl<T, I> RotateLeft<I> for T
+where
+    T: SplitAt<I>,
+    T::Suffix: Extend,
+    I: Index,
+{
+    type Output = <T::Suffix as Extend>::Output<T::Prefix>;
+
+    fn rotate_left(self) -> Self::Output {
+        let (prefix, suffix) = self.split_at();
+        suffix.extend(prefix)
+    }
+}
+
+/// Rotate elements of the homogenous list in place by a runtime amount.
+///
+/// Unlike [`RotateLeft`], type of the list is not changed
+/// because all elements of the homogenous list have the same type.
+pub trait RotateRuntime: HList {
+    /// Rotates elements of the homogenous list in place to the left by `n` positions.
+    ///
+    /// Amount of rotation is taken modulo the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::RotateRuntime};
+    ///
+    /// let mut list = hlist![1, 2, 3, 4];
+    /// list.rotate_left_by(1);
+    /// assert_eq!(list, hlist![2, 3, 4, 1]);
+    ///
+    /// list.rotate_left_by(6);
+    /// assert_eq!(list, hlist![4, 1, 2, 3]);
+    ///
+    /// list.rotate_left_by(0);
+    /// assert_eq!(list, hlist![4, 1, 2, 3]);
+    ///
+    /// list.rotate_left_by(3);
+    /// assert_eq!(list, hlist![3, 4, 1, 2]);
+    /// ```
+    fn rotate_left_by(&mut self, n: usize);
+
+    /// Rotates elements of the homogenous list in place to the right by `n` positions.
+    ///
+    /// Amount of rotation is taken modulo the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::RotateRuntime};
+    ///
+    /// let mut list = hlist![1, 2, 3, 4];
+    /// list.rotate_right_by(1);
+    /// assert_eq!(list, hlist![4, 1, 2, 3]);
+    ///
+    /// list.rotate_right_by(7);
+    /// assert_eq!(list, hlist![1, 2, 3, 4]);
+    ///
+    /// list.rotate_right_by(4);
+    /// assert_eq!(list, hlist![1, 2, 3, 4]);
+    /// ```
+    fn rotate_right_by(&mut self, n: usize);
+}
+
+impl RotateRuntime for Nil {
+    fn rotate_left_by(&mut self, _: usize) {}
+
+    fn rotate_right_by(&mut self, _: usize) {}
+}
+
+impl<Head, Tail> RotateRuntime for Cons<Head, Tail>
+where
+    Tail: HList,
+    for<'a> &'a mut Self: IntoIterator<Item = &'a mut Head>,
+    for<'a> <&'a mut Self as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    fn rotate_left_by(&mut self, n: usize) {
+        let len = self.len();
+        let n = n % len;
+        if n > len / 2 {
+            for _ in n..len {
+                rotate_right_once(&mut *self);
+            }
+            return;
+        }
+        for _ in 0..n {
+            rotate_left_once(&mut *self);
+        }
+    }
+
+    fn rotate_right_by(&mut self, n: usize) {
+        let len = self.len();
+        self.rotate_left_by(len - n % len)
+    }
+}
+
+fn rotate_left_once<'a, T, I>(list: I)
+where
+    T: 'a,
+    I: IntoIterator<Item = &'a mut T>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut iter = list.into_iter();
+    let Some(first) = iter.next() else { return };
+    iter.rev().for_each(|item| swap(first, item))
+}
+
+fn rotate_right_once<'a, T, I>(list: I)
+where
+    T: 'a,
+    I: IntoIterator<Item = &'a mut T>,
+{
+    let mut iter = list.into_iter();
+    let Some(first) = iter.next() else { return };
+    iter.for_each(|item| swap(first, item))
+}
@@ -0,0 +1,45 @@
+use super::{Index, Position, RotateLeft};
+
+/// Rotate elements of the heterogenous list so the element of provided type becomes the first one.
+///
+/// Unlike [`RotateLeft`] trait, this trait finds the index of the element by its type.
+pub trait RotateToType<T, I>: Position<T, I> + RotateLeft<I>
+where
+    I: Index,
+{
+    /// Rotates elements of the heterogenous list so the element of provided type becomes the first one.
+    ///
+    /// Relative cyclic order of all the elements is preserved:
+    /// elements before the element of provided type are moved to the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::RotateToType};
+    ///
+    /// let list = hlist![1_i32, 2.0, true];
+    /// let rotated = RotateToType::<bool, _>::rotate_to(list);
+    /// assert_eq!(rotated, hlist![true, 1, 2.0]);
+    /// ```
+    ///
+    /// If the element is already the first one, list is left unchanged:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::RotateToType};
+    ///
+    /// let list = hlist![1_i32, 2.0, true];
+    /// let rotated = RotateToType::<i32, _>::rotate_to(list);
+    /// assert_eq!(rotated, hlist![1, 2.0, true]);
+    /// ```
+    fn rotate_to(self) -> Self::Output;
+}
+
+impl<L, T, I> RotateToType<T, I> for L
+where
+    L: Position<T, I> + RotateLeft<I>,
+    I: Index,
+{
+    fn rotate_to(self) -> Self::Output {
+        self.rotate_left()
+    }
+}
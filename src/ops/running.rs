@@ -0,0 +1,106 @@
+use crate::{Cons, HList, Nil};
+
+/// Compute running maximum of homogenous heterogenous list.
+pub trait RunningMax: HList {
+    /// Computes running maximum of homogenous heterogenous list.
+    ///
+    /// Each element of the new list is the maximum of all preceding elements including itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::RunningMax};
+    ///
+    /// let list = hlist![1, 3, 2, 5];
+    /// assert_eq!(list.running_max(), hlist![1, 3, 3, 5]);
+    ///
+    /// let list = hlist![4, 3, 2, 1];
+    /// assert_eq!(list.running_max(), hlist![4, 4, 4, 4]);
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// assert_eq!(list.running_max(), hlist![1, 2, 3, 4]);
+    /// ```
+    fn running_max(self) -> Self;
+}
+
+impl RunningMax for Nil {
+    fn running_max(self) -> Self {
+        self
+    }
+}
+
+impl<T, Tail> RunningMax for Cons<T, Tail>
+where
+    T: Ord + Copy,
+    Tail: Running<T>,
+{
+    fn running_max(self) -> Self {
+        let Cons(head, tail) = self;
+        let tail = tail.running(head, Ord::max);
+        Cons(head, tail)
+    }
+}
+
+/// Compute running minimum of homogenous heterogenous list.
+pub trait RunningMin: HList {
+    /// Computes running minimum of homogenous heterogenous list.
+    ///
+    /// Each element of the new list is the minimum of all preceding elements including itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::RunningMin};
+    ///
+    /// let list = hlist![3, 1, 2, 0];
+    /// assert_eq!(list.running_min(), hlist![3, 1, 1, 0]);
+    ///
+    /// let list = hlist![4, 3, 2, 1];
+    /// assert_eq!(list.running_min(), hlist![4, 3, 2, 1]);
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// assert_eq!(list.running_min(), hlist![1, 1, 1, 1]);
+    /// ```
+    fn running_min(self) -> Self;
+}
+
+impl RunningMin for Nil {
+    fn running_min(self) -> Self {
+        self
+    }
+}
+
+impl<T, Tail> RunningMin for Cons<T, Tail>
+where
+    T: Ord + Copy,
+    Tail: Running<T>,
+{
+    fn running_min(self) -> Self {
+        let Cons(head, tail) = self;
+        let tail = tail.running(head, Ord::min);
+        Cons(head, tail)
+    }
+}
+
+pub trait Running<T>: HList {
+    fn running(self, current: T, pick: fn(T, T) -> T) -> Self;
+}
+
+impl<T> Running<T> for Nil {
+    fn running(self, _: T, _: fn(T, T) -> T) -> Self {
+        self
+    }
+}
+
+impl<T, Tail> Running<T> for Cons<T, Tail>
+where
+    T: Copy,
+    Tail: Running<T>,
+{
+    fn running(self, current: T, pick: fn(T, T) -> T) -> Self {
+        let Cons(head, tail) = self;
+        let head = pick(current, head);
+        let tail = tail.running(head, pick);
+        Cons(head, tail)
+    }
+}
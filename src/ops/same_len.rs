@@ -0,0 +1,52 @@
+use crate::{Cons, HList, Nil};
+
+/// Heterogenous list with the same length (count of elements) as other heterogenous list.
+///
+/// This trait is implemented recursively: [`Nil`] has the same length as [`Nil`],
+/// and [`Cons`] has the same length as other [`Cons`] if their tails have the same length.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{hlist, ops::{SameLenAs, Zip}, HList};
+///
+/// fn zip_checked<A, B>(a: A, b: B) -> A::Output
+/// where
+///     A: SameLenAs<B> + Zip<B>,
+///     B: HList,
+/// {
+///     a.zip(b)
+/// }
+///
+/// let zipped = zip_checked(hlist![1, 2.0], hlist![true, "x"]);
+/// assert_eq!(zipped, hlist![(1, true), (2.0, "x")]);
+/// ```
+///
+/// Lists of different lengths do not satisfy such bound:
+///
+/// ```compile_fail
+/// use hlist2::{hlist, ops::SameLenAs, HList};
+///
+/// fn same_len<A, B>(a: A, b: B)
+/// where
+///     A: SameLenAs<B>,
+///     B: HList,
+/// {
+/// }
+///
+/// same_len(hlist![1, 2.0], hlist![true]);
+/// ```
+pub trait SameLenAs<Other>: HList
+where
+    Other: HList,
+{
+}
+
+impl SameLenAs<Nil> for Nil {}
+
+impl<Head, Tail, OtherHead, OtherTail> SameLenAs<Cons<OtherHead, OtherTail>> for Cons<Head, Tail>
+where
+    Tail: SameLenAs<OtherTail>,
+    OtherTail: HList,
+{
+}
@@ -0,0 +1,81 @@
+use crate::{Cons, HList, Nil};
+
+use super::Extend;
+
+/// Produce cumulative concatenations of heterogenous list of heterogenous lists.
+pub trait ScanConcat: HList {
+    /// Type of heterogenous list of cumulative concatenations.
+    type Output: HList;
+
+    /// Produces cumulative concatenations of heterogenous list of heterogenous lists.
+    ///
+    /// Each element of the resulting list is the concatenation of the corresponding inner list
+    /// with all the inner lists before it, so each next element grows by the length of
+    /// the corresponding inner list. Last element of the resulting list
+    /// is the concatenation of all the inner lists.
+    ///
+    /// Concatenations are cloned on each step, so elements of inner lists must implement [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ScanConcat};
+    ///
+    /// let list = hlist![hlist![1], hlist![2, 3], hlist![4]];
+    /// let scanned = list.scan_concat();
+    /// assert_eq!(scanned, hlist![hlist![1], hlist![1, 2, 3], hlist![1, 2, 3, 4]]);
+    ///
+    /// let list = hlist![hlist![1, 2.0], hlist![], hlist![true]];
+    /// let scanned = list.scan_concat();
+    /// assert_eq!(scanned, hlist![hlist![1, 2.0], hlist![1, 2.0], hlist![1, 2.0, true]]);
+    /// ```
+    fn scan_concat(self) -> Self::Output;
+}
+
+impl<T> ScanConcat for T
+where
+    T: ScanConcatWith<Nil>,
+{
+    type Output = T::Output;
+
+    fn scan_concat(self) -> Self::Output {
+        self.scan_concat_with(Nil)
+    }
+}
+
+pub trait ScanConcatWith<Done>: HList
+where
+    Done: HList,
+{
+    type Output: HList;
+
+    fn scan_concat_with(self, done: Done) -> Self::Output;
+}
+
+impl<Done> ScanConcatWith<Done> for Nil
+where
+    Done: HList,
+{
+    type Output = Nil;
+
+    fn scan_concat_with(self, _: Done) -> Self::Output {
+        self
+    }
+}
+
+impl<Done, Head, Tail> ScanConcatWith<Done> for Cons<Head, Tail>
+where
+    Done: Extend,
+    Done::Output<Head>: Clone,
+    Head: HList,
+    Tail: ScanConcatWith<Done::Output<Head>>,
+{
+    type Output = Cons<Done::Output<Head>, Tail::Output>;
+
+    fn scan_concat_with(self, done: Done) -> Self::Output {
+        let Cons(head, tail) = self;
+        let head = done.extend(head);
+        let tail = tail.scan_concat_with(head.clone());
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Cons, HList, Nil};
+
+/// Produce all intermediate accumulators of folding the homogenous list, including initial one.
+pub trait ScanL<Accumulator, Folder>: HList {
+    /// Type of new homogenous list of accumulators.
+    type Output: HList;
+
+    /// Folds every element of the homogenous list into an accumulator,
+    /// collecting each intermediate accumulator into new homogenous list.
+    ///
+    /// Unlike a plain scan, which produces only accumulators obtained after each element,
+    /// initial accumulator is included as the first element of the output.
+    /// So resulting list has length `LEN + 1`, and its last element is the result of the fold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ScanL};
+    ///
+    /// let list = hlist![1, 2, 3];
+    /// let scanned = list.scanl(0, |acc, x| acc + x);
+    /// assert_eq!(scanned, hlist![0, 1, 3, 6]);
+    ///
+    /// let scanned = hlist![].scanl(42, |acc: i32, x: i32| acc + x);
+    /// assert_eq!(scanned, hlist![42]);
+    /// ```
+    fn scanl(self, init: Accumulator, folder: Folder) -> Self::Output;
+}
+
+impl<A, F> ScanL<A, F> for Nil {
+    type Output = Cons<A, Nil>;
+
+    fn scanl(self, init: A, _: F) -> Self::Output {
+        Cons(init, self)
+    }
+}
+
+impl<A, F, Head, Tail> ScanL<A, F> for Cons<Head, Tail>
+where
+    A: Clone,
+    F: FnMut(A, Head) -> A,
+    Tail: ScanL<A, F>,
+{
+    type Output = Cons<A, Tail::Output>;
+
+    fn scanl(self, init: A, mut folder: F) -> Self::Output {
+        let Cons(head, tail) = self;
+        let next = folder(init.clone(), head);
+        let tail = tail.scanl(next, folder);
+        Cons(init, tail)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{Cons, HList, Nil};
+
+use super::{Index, RotateLeft};
+
+/// Rotate rows of the heterogenous list of heterogenous lists to the left.
+///
+/// This is the same as [`RotateLeft`] trait, but it additionally requires
+/// each element of the heterogenous list to be a heterogenous list too.
+pub trait ShiftRows<I>: RotateLeft<I>
+where
+    I: Index,
+{
+    /// Rotates rows of the heterogenous list of heterogenous lists to the left by an index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, ShiftRows, There}};
+    ///
+    /// let grid = hlist![hlist![1], hlist![2], hlist![3]];
+    /// let shifted = ShiftRows::<There<Here>>::shift_rows(grid);
+    /// assert_eq!(shifted, hlist![hlist![2], hlist![3], hlist![1]]);
+    /// ```
+    ///
+    /// Each element of the list must be a heterogenous list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{Here, ShiftRows, There}};
+    ///
+    /// let grid = hlist![hlist![1], 2, hlist![3]];
+    /// let shifted = ShiftRows::<There<Here>>::shift_rows(grid);
+    /// ```
+    fn shift_rows(self) -> Self::Output;
+}
+
+impl<T, I> ShiftRows<I> for T
+where
+    T: RotateLeft<I> + Rows,
+    I: Index,
+{
+    fn shift_rows(self) -> Self::Output {
+        self.rotate_left()
+    }
+}
+
+pub trait Rows: HList {}
+
+impl Rows for Nil {}
+
+impl<Head, Tail> Rows for Cons<Head, Tail>
+where
+    Head: HList,
+    Tail: Rows,
+{
+}
@@ -0,0 +1,62 @@
+use core::ops::Sub;
+
+use crate::HList;
+
+use super::{Index, SplitAt};
+
+/// Extract a sub-range of elements of the heterogenous list.
+pub trait Slice<Start, End>: HList
+where
+    Start: Index,
+    End: Index,
+{
+    /// Type of heterogenous list with elements of the sub-range.
+    type Output: HList;
+
+    /// Extracts elements of the heterogenous list in the half-open range `[Start, End)`.
+    ///
+    /// Start index cannot be greater than the end index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, Slice, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let slice = Slice::<There<Here>, There<There<There<Here>>>>::slice(list);
+    /// assert_eq!(slice, hlist![2.0, true]);
+    ///
+    /// let slice = Slice::<There<Here>, There<Here>>::slice(list);
+    /// assert_eq!(slice, hlist![]);
+    ///
+    /// let slice = Slice::<Here, There<There<There<There<Here>>>>>::slice(list);
+    /// assert_eq!(slice, list);
+    /// ```
+    ///
+    /// Start index cannot be greater than the end index:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{Here, Slice, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let slice = Slice::<There<There<Here>>, There<Here>>::slice(list);
+    /// ```
+    fn slice(self) -> Self::Output;
+}
+
+impl<T, Start, End> Slice<Start, End> for T
+where
+    T: SplitAt<Start>,
+    T::Suffix: SplitAt<End::Output>,
+    Start: Index,
+    End: Index + Sub<Start>,
+    End::Output: Index,
+{
+    type Output = <T::Suffix as SplitAt<End::Output>>::Prefix;
+
+    fn slice(self) -> Self::Output {
+        let (_, suffix) = self.split_at();
+        let (slice, _) = suffix.split_at();
+        slice
+    }
+}
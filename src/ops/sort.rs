@@ -0,0 +1,69 @@
+use core::cmp::Ordering;
+
+use alloc::vec::Vec;
+
+use crate::{iter::Homogenous, Len};
+
+/// Collect elements of the homogenous list into a sorted [vector](Vec).
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait IntoSorted: Homogenous + Len {
+    /// Collects elements of the homogenous list into a [vector](Vec) sorted in ascending order.
+    ///
+    /// Sorting of elements at the type level is not feasible,
+    /// so elements are sorted at runtime. This sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::IntoSorted};
+    ///
+    /// let list = hlist![3, 1, 2];
+    /// assert_eq!(list.into_sorted_vec(), [1, 2, 3]);
+    /// ```
+    fn into_sorted_vec(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord;
+
+    /// Collects elements of the homogenous list into a [vector](Vec)
+    /// sorted with a comparator function.
+    ///
+    /// This sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::IntoSorted};
+    ///
+    /// let list = hlist![3, 1, 2];
+    /// assert_eq!(list.into_sorted_by(|a, b| b.cmp(a)), [3, 2, 1]);
+    ///
+    /// let list = hlist!["ccc", "a", "bb"];
+    /// assert_eq!(list.into_sorted_by(|a, b| a.len().cmp(&b.len())), ["a", "bb", "ccc"]);
+    /// ```
+    fn into_sorted_by<F>(self, compare: F) -> Vec<Self::Item>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+}
+
+impl<T> IntoSorted for T
+where
+    T: Homogenous + Len,
+{
+    fn into_sorted_vec(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        self.into_sorted_by(Ord::cmp)
+    }
+
+    fn into_sorted_by<F>(self, compare: F) -> Vec<Self::Item>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut vec = Vec::with_capacity(T::LEN);
+        vec.extend(self);
+        vec.sort_by(compare);
+        vec
+    }
+}
@@ -0,0 +1,133 @@
+use core::ops::Sub;
+
+use crate::HList;
+
+use super::{Extend, Index, SplitAt};
+
+/// Replace a sub-range of elements of the heterogenous list with another heterogenous list.
+pub trait SpliceAt<Start, End>: HList
+where
+    Start: Index,
+    End: Index,
+{
+    /// Type of new heterogenous list with replaced sub-range.
+    type Output<R>: HList
+    where
+        R: Extend;
+
+    /// Replaces elements of the heterogenous list in the half-open range `[Start, End)`
+    /// with elements of provided heterogenous list.
+    ///
+    /// Replacement list can have any length and any types of elements.
+    /// Start index cannot be greater than the end index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, SpliceAt, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let list = SpliceAt::<There<Here>, There<There<Here>>>::splice_at(list, hlist![9u8, 'a']);
+    /// assert_eq!(list, hlist![1, 9u8, 'a', true, "x"]);
+    /// ```
+    fn splice_at<R>(self, replacement: R) -> Self::Output<R>
+    where
+        R: Extend;
+}
+
+impl<T, Start, End> SpliceAt<Start, End> for T
+where
+    T: SplitAt<Start>,
+    T::Prefix: Extend,
+    T::Suffix: SplitAt<End::Output>,
+    Start: Index,
+    End: Index + Sub<Start>,
+    End::Output: Index,
+{
+    type Output<R>
+        = <T::Prefix as Extend>::Output<
+        <R as Extend>::Output<<T::Suffix as SplitAt<End::Output>>::Suffix>,
+    >
+    where
+        R: Extend;
+
+    fn splice_at<R>(self, replacement: R) -> Self::Output<R>
+    where
+        R: Extend,
+    {
+        let (prefix, suffix) = self.split_at();
+        let (_, rest) = suffix.split_at();
+        prefix.extend(replacement.extend(rest))
+    }
+}
+
+/// Replace a sub-range of elements of the heterogenous list with another heterogenous list.
+pub trait Splice: HList {
+    /// Replaces elements of the heterogenous list in the half-open range `[Start, End)`
+    /// with elements of provided heterogenous list.
+    ///
+    /// This is the same as [`SpliceAt::splice_at`],
+    /// but indices of the range can be provided with turbofish syntax on the method.
+    ///
+    /// # Examples
+    ///
+    /// Replacement of the range:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, Splice, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let list = list.splice::<There<Here>, There<There<Here>>, _>(hlist![9u8]);
+    /// assert_eq!(list, hlist![1, 9u8, true, "x"]);
+    /// ```
+    ///
+    /// Insertion with an empty range:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, Splice, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let list = list.splice::<There<Here>, There<Here>, _>(hlist!['a', 'b']);
+    /// assert_eq!(list, hlist![1, 'a', 'b', 2.0, true, "x"]);
+    /// ```
+    ///
+    /// Deletion with an empty replacement:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, Splice, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let list = list.splice::<Here, There<There<Here>>, _>(hlist![]);
+    /// assert_eq!(list, hlist![true, "x"]);
+    /// ```
+    ///
+    /// Start index cannot be greater than the end index:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{Here, Splice, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let list = list.splice::<There<There<Here>>, There<Here>, _>(hlist![9u8]);
+    /// ```
+    fn splice<Start, End, R>(self, replacement: R) -> <Self as SpliceAt<Start, End>>::Output<R>
+    where
+        Self: SpliceAt<Start, End>,
+        Start: Index,
+        End: Index,
+        R: Extend;
+}
+
+impl<L> Splice for L
+where
+    L: HList,
+{
+    fn splice<Start, End, R>(self, replacement: R) -> <Self as SpliceAt<Start, End>>::Output<R>
+    where
+        Self: SpliceAt<Start, End>,
+        Start: Index,
+        End: Index,
+        R: Extend,
+    {
+        self.splice_at(replacement)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{Cons, HList, Nil};
+
+use super::{Here, Index, There};
+
+/// Split the heterogenous list into two at an index.
+pub trait SplitAt<I>: HList
+where
+    I: Index,
+{
+    /// Type of heterogenous list with elements before provided index.
+    type Prefix: HList;
+    /// Type of heterogenous list with elements starting from provided index.
+    type Suffix: HList;
+
+    /// Splits the heterogenous list into two at an index.
+    ///
+    /// The first list will contain all elements before provided index (excluding),
+    /// and the second list will contain all elements starting from provided index (including).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::{Here, SplitAt, There}};
+    ///
+    /// let list = hlist![1, 2.0, true, "hello world"];
+    /// let (prefix, suffix) = SplitAt::<There<There<Here>>>::split_at(list);
+    /// assert_eq!(prefix, hlist![1, 2.0]);
+    /// assert_eq!(suffix, hlist![true, "hello world"]);
+    /// ```
+    ///
+    /// Index cannot be greater than the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::{Here, SplitAt, There}};
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let (prefix, suffix) = SplitAt::<There<There<There<Here>>>>::split_at(list);
+    /// ```
+    fn split_at(self) -> (Self::Prefix, Self::Suffix);
+}
+
+impl<T> SplitAt<Here> for T
+where
+    T: HList,
+{
+    type Prefix = Nil;
+    type Suffix = T;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        (Nil, self)
+    }
+}
+
+impl<Head, Tail, TailIndex> SplitAt<There<TailIndex>> for Cons<Head, Tail>
+where
+    Tail: SplitAt<TailIndex>,
+    TailIndex: Index,
+{
+    type Prefix = Cons<Head, Tail::Prefix>;
+    type Suffix = Tail::Suffix;
+
+    fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+        let Cons(head, tail) = self;
+        let (prefix, suffix) = tail.split_at();
+        let prefix = Cons(head, prefix);
+        (prefix, suffix)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Cons, HList};
+
+use super::Pop;
+
+/// Split the heterogenous list into the first element, the middle part and the last element.
+pub trait SplitEnds: HList {
+    /// The first element of the heterogenous list.
+    type First;
+    /// Middle part of the heterogenous list without the first and the last elements.
+    type Middle: HList;
+    /// The last element of the heterogenous list.
+    type Last;
+
+    /// Splits the heterogenous list into the first element, the middle part and the last element.
+    ///
+    /// Heterogenous list must contain at least two elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::SplitEnds};
+    ///
+    /// let list = hlist![1, 2.0, true, "x"];
+    /// let (first, middle, last) = list.split_ends();
+    /// assert_eq!(first, 1);
+    /// assert_eq!(middle, hlist![2.0, true]);
+    /// assert_eq!(last, "x");
+    ///
+    /// let list = hlist![1, 2.0];
+    /// let (first, middle, last) = list.split_ends();
+    /// assert_eq!((first, middle, last), (1, hlist![], 2.0));
+    /// ```
+    ///
+    /// Heterogenous list with less than two elements cannot be split:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::SplitEnds};
+    ///
+    /// let list = hlist![1];
+    /// let (first, middle, last) = list.split_ends();
+    /// ```
+    fn split_ends(self) -> (Self::First, Self::Middle, Self::Last);
+}
+
+impl<Head, Tail> SplitEnds for Cons<Head, Tail>
+where
+    Tail: Pop,
+{
+    type First = Head;
+    type Middle = Tail::Remainder;
+    type Last = Tail::Last;
+
+    fn split_ends(self) -> (Self::First, Self::Middle, Self::Last) {
+        let Cons(first, tail) = self;
+        let (last, middle) = tail.pop();
+        (first, middle, last)
+    }
+}
@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+
+use crate::iter::Homogenous;
+
+/// Split homogenous list of results into successes and failures.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait SplitResults<T, E>: Homogenous<Item = Result<T, E>> {
+    /// Partitions elements of the homogenous list of results
+    /// into [vector](Vec) of successes and [vector](Vec) of failures.
+    ///
+    /// Relative order of elements is preserved in both vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::SplitResults};
+    ///
+    /// let list = hlist![Ok(1), Err("e"), Ok(2)];
+    /// assert_eq!(list.split_results(), (vec![1, 2], vec!["e"]));
+    ///
+    /// let list = hlist![Ok::<_, &str>(1), Ok(2)];
+    /// assert_eq!(list.split_results(), (vec![1, 2], vec![]));
+    ///
+    /// let list = hlist![Err::<i32, _>("a"), Err("b")];
+    /// assert_eq!(list.split_results(), (vec![], vec!["a", "b"]));
+    /// ```
+    fn split_results(self) -> (Vec<T>, Vec<E>);
+}
+
+impl<T, E, L> SplitResults<T, E> for L
+where
+    L: Homogenous<Item = Result<T, E>>,
+{
+    fn split_results(self) -> (Vec<T>, Vec<E>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for result in self {
+            match result {
+                Ok(ok) => oks.push(ok),
+                Err(err) => errs.push(err),
+            }
+        }
+        (oks, errs)
+    }
+}
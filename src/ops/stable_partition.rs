@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+
+use crate::{iter::Homogenous, Len};
+
+/// Move elements of the homogenous list satisfying the predicate to the front.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait StablePartition: Homogenous + Len {
+    /// Collects elements of the homogenous list into a [vector](Vec)
+    /// where all the elements satisfying the predicate come first.
+    ///
+    /// Relative order of elements is preserved within each group.
+    /// Resulting vector is returned because sizes of groups are known only at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::StablePartition};
+    ///
+    /// let list = hlist![1, 2, 3, 4, 5, 6];
+    /// assert_eq!(list.stable_partition(|x| x % 2 == 0), [2, 4, 6, 1, 3, 5]);
+    /// assert_eq!(list.stable_partition(|&x| x > 4), [5, 6, 1, 2, 3, 4]);
+    /// assert_eq!(list.stable_partition(|&x| x > 0), [1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(list.stable_partition(|&x| x > 6), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn stable_partition<P>(self, pred: P) -> Vec<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool;
+}
+
+impl<T> StablePartition for T
+where
+    T: Homogenous + Len,
+{
+    fn stable_partition<P>(self, mut pred: P) -> Vec<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let (mut matching, rest): (Vec<_>, Vec<_>) = self.into_iter().partition(|item| pred(item));
+        matching.reserve_exact(T::LEN - matching.len());
+        matching.extend(rest);
+        matching
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{Cons, HList, Len, Nil};
+
+/// Get lengths of each heterogenous list of the heterogenous list of heterogenous lists.
+pub trait SublistLengths: HList {
+    /// Type of new homogenous list of lengths.
+    type Output: HList;
+
+    /// Returns homogenous list of [lengths](Len::LEN) of each inner heterogenous list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::SublistLengths};
+    ///
+    /// let list = hlist![hlist![1, 2], hlist![3.0], hlist![], hlist![true, 'x', "y"]];
+    /// let lengths: HList![usize, usize, usize, usize] = list.sublist_lengths();
+    /// assert_eq!(lengths, hlist![2, 1, 0, 3]);
+    ///
+    /// let total: usize = lengths.into_iter().sum();
+    /// assert_eq!(total, 6);
+    /// ```
+    fn sublist_lengths(&self) -> Self::Output;
+}
+
+impl SublistLengths for Nil {
+    type Output = Nil;
+
+    fn sublist_lengths(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl<Head, Tail> SublistLengths for Cons<Head, Tail>
+where
+    Head: Len,
+    Tail: SublistLengths,
+{
+    type Output = Cons<usize, Tail::Output>;
+
+    fn sublist_lengths(&self) -> Self::Output {
+        let Cons(_, tail) = self;
+        let tail = tail.sublist_lengths();
+        Cons(Head::LEN, tail)
+    }
+}
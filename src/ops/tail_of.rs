@@ -0,0 +1,37 @@
+use crate::{Cons, HList};
+
+/// Name the head and the tail types of non-empty heterogenous list in generic code.
+///
+/// This trait is useful to name these types in bounds and return positions
+/// without pattern binding of the list.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{hlist, ops::TailOf, Cons};
+///
+/// fn drop_head<L>(list: L) -> <L as TailOf>::Tail
+/// where
+///     L: TailOf + Into<Cons<L::Head, L::Tail>>,
+/// {
+///     let Cons(_, tail) = list.into();
+///     tail
+/// }
+///
+/// let tail = drop_head(hlist![1, 2.0, true]);
+/// assert_eq!(tail, hlist![2.0, true]);
+/// ```
+pub trait TailOf: HList {
+    /// Type of the first element of the heterogenous list.
+    type Head;
+    /// Type of the remaining part of the heterogenous list without the first element.
+    type Tail: HList;
+}
+
+impl<Head, Tail> TailOf for Cons<Head, Tail>
+where
+    Tail: HList,
+{
+    type Head = Head;
+    type Tail = Tail;
+}
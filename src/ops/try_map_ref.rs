@@ -0,0 +1,96 @@
+use crate::{Cons, HList, Nil};
+
+/// Fallibly transform references to elements of one heterogenous list into another.
+pub trait TryMapRef<Mapper, Error>: HList {
+    /// Type of new heterogenous list after successful transformation.
+    type Output: HList;
+
+    /// Fallibly transforms the heterogenous list into another heterogenous list
+    /// by applying an operation to the reference of each element by mapper.
+    ///
+    /// Source heterogenous list is left intact.
+    /// Transformation stops at the first error, which is returned then.
+    ///
+    /// # Examples
+    ///
+    /// You can map the list if it is homogenous (all elements have the same type):
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::TryMapRef};
+    ///
+    /// let list = hlist!["1", "2"];
+    /// let parsed = list.try_map_ref(|s: &&str| s.parse::<i32>());
+    /// assert_eq!(parsed, Ok(hlist![1, 2]));
+    /// assert_eq!(list, hlist!["1", "2"]);
+    /// ```
+    ///
+    /// Transformation is stopped on the first error, so next elements are not visited:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::TryMapRef};
+    ///
+    /// let list = hlist!["1", "x", "3"];
+    /// let mut visited = 0;
+    /// let parsed = list.try_map_ref(|s: &&str| {
+    ///     visited += 1;
+    ///     s.parse::<i32>()
+    /// });
+    /// assert!(parsed.is_err());
+    /// assert_eq!(visited, 2);
+    /// ```
+    ///
+    /// Mapping of heterogenous list is possible with heterogenous list of closures as mapper:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::TryMapRef};
+    ///
+    /// let list = hlist!["1", 2.0];
+    /// let mapped = list.try_map_ref(
+    ///     hlist![
+    ///         |s: &&str| s.parse::<i32>().map_err(|_| "not an integer"),
+    ///         |f: &f64| if f.is_finite() { Ok(f * 2.0) } else { Err("not finite") },
+    ///     ]
+    /// );
+    /// assert_eq!(mapped, Ok(hlist![1, 4.0]));
+    /// ```
+    fn try_map_ref(&self, mapper: Mapper) -> Result<Self::Output, Error>;
+}
+
+impl<M, E> TryMapRef<M, E> for Nil {
+    type Output = Nil;
+
+    fn try_map_ref(&self, _: M) -> Result<Self::Output, E> {
+        Ok(Nil)
+    }
+}
+
+impl<M, E, R, Head, Tail> TryMapRef<M, E> for Cons<Head, Tail>
+where
+    M: FnMut(&Head) -> Result<R, E>,
+    Tail: TryMapRef<M, E>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn try_map_ref(&self, mut mapper: M) -> Result<Self::Output, E> {
+        let Cons(head, tail) = self;
+        let head = mapper(head)?;
+        let tail = tail.try_map_ref(mapper)?;
+        Ok(Cons(head, tail))
+    }
+}
+
+impl<MHead, MTail, E, R, Head, Tail> TryMapRef<Cons<MHead, MTail>, E> for Cons<Head, Tail>
+where
+    MHead: FnOnce(&Head) -> Result<R, E>,
+    Tail: TryMapRef<MTail, E>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn try_map_ref(&self, mapper: Cons<MHead, MTail>) -> Result<Self::Output, E> {
+        let Cons(head, tail) = self;
+        let Cons(mapper_head, mapper_tail) = mapper;
+        let head = mapper_head(head)?;
+        let tail = tail.try_map_ref(mapper_tail)?;
+        Ok(Cons(head, tail))
+    }
+}
@@ -0,0 +1,57 @@
+use alloc::{string::String, vec::Vec};
+use core::any::type_name;
+
+use crate::{Cons, HList, Nil};
+
+/// Describe types of elements of the heterogenous list as a string.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait TypeSignature: HList {
+    /// Returns names of types of elements of the heterogenous list joined by a comma.
+    ///
+    /// Names are obtained by [`type_name`] function,
+    /// so the same caveats apply: exact contents of the string are not guaranteed to be stable,
+    /// so it should be used for diagnostic purposes only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::TypeSignature};
+    ///
+    /// let list = hlist![1_i32, 2.0_f64, true];
+    /// assert_eq!(list.type_signature(), "i32, f64, bool");
+    ///
+    /// let list = hlist![];
+    /// assert_eq!(list.type_signature(), "");
+    /// ```
+    fn type_signature(&self) -> String;
+}
+
+impl<T> TypeSignature for T
+where
+    T: TypeNames,
+{
+    fn type_signature(&self) -> String {
+        let mut names = Vec::new();
+        T::type_names(&mut names);
+        names.join(", ")
+    }
+}
+
+pub trait TypeNames: HList {
+    fn type_names(names: &mut Vec<&'static str>);
+}
+
+impl TypeNames for Nil {
+    fn type_names(_: &mut Vec<&'static str>) {}
+}
+
+impl<Head, Tail> TypeNames for Cons<Head, Tail>
+where
+    Tail: TypeNames,
+{
+    fn type_names(names: &mut Vec<&'static str>) {
+        names.push(type_name::<Head>());
+        Tail::type_names(names)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{Cons, HList, Nil};
+
+/// Construct homogenous heterogenous list by repeated application of a generator.
+///
+/// This is, in some sense, the opposite of [`Fold`](crate::ops::Fold) trait.
+pub trait Unfold<T>: HList {
+    /// Constructs homogenous heterogenous list by calling the generator once per element.
+    ///
+    /// Generator is called exactly [`LEN`](crate::Len::LEN) times
+    /// with a mutable reference to the state, which is threaded through all the calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::Unfold};
+    ///
+    /// let mut calls = 0;
+    /// let list = <HList![i32, i32, i32]>::unfold(0, |state| {
+    ///     calls += 1;
+    ///     *state += 1;
+    ///     *state
+    /// });
+    /// assert_eq!(list, hlist![1, 2, 3]);
+    /// assert_eq!(calls, 3);
+    /// ```
+    fn unfold<State, F>(init: State, generator: F) -> Self
+    where
+        F: FnMut(&mut State) -> T;
+
+    /// Fallibly constructs homogenous heterogenous list by calling the generator once per element.
+    ///
+    /// Construction stops at the first `None` returned by the generator,
+    /// so `None` is returned if the generator was exhausted before filling all the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, HList, ops::Unfold};
+    ///
+    /// let list = <HList![i32, i32, i32]>::try_unfold(0, |state| {
+    ///     *state += 1;
+    ///     Some(*state)
+    /// });
+    /// assert_eq!(list, Some(hlist![1, 2, 3]));
+    ///
+    /// let mut calls = 0;
+    /// let list = <HList![i32, i32, i32]>::try_unfold(0, |state| {
+    ///     calls += 1;
+    ///     *state += 1;
+    ///     (*state < 2).then_some(*state)
+    /// });
+    /// assert_eq!(list, None);
+    /// assert_eq!(calls, 2);
+    /// ```
+    fn try_unfold<State, F>(init: State, generator: F) -> Option<Self>
+    where
+        Self: Sized,
+        F: FnMut(&mut State) -> Option<T>;
+}
+
+impl<T> Unfold<T> for Nil {
+    fn unfold<State, F>(_: State, _: F) -> Self
+    where
+        F: FnMut(&mut State) -> T,
+    {
+        Nil
+    }
+
+    fn try_unfold<State, F>(_: State, _: F) -> Option<Self>
+    where
+        F: FnMut(&mut State) -> Option<T>,
+    {
+        Some(Nil)
+    }
+}
+
+impl<T, Tail> Unfold<T> for Cons<T, Tail>
+where
+    Tail: Unfold<T>,
+{
+    fn unfold<State, F>(init: State, mut generator: F) -> Self
+    where
+        F: FnMut(&mut State) -> T,
+    {
+        let mut state = init;
+        let head = generator(&mut state);
+        let tail = Tail::unfold(state, generator);
+        Cons(head, tail)
+    }
+
+    fn try_unfold<State, F>(init: State, mut generator: F) -> Option<Self>
+    where
+        F: FnMut(&mut State) -> Option<T>,
+    {
+        let mut state = init;
+        let head = generator(&mut state)?;
+        let tail = Tail::try_unfold(state, generator)?;
+        Some(Cons(head, tail))
+    }
+}
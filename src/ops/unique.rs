@@ -0,0 +1,63 @@
+use crate::{Cons, HList, Nil};
+
+use super::{Get, Index, ManyIndex};
+
+/// Heterogenous list which contains only distinct types of elements,
+/// as long as index generic parameter is inferred by the compiler.
+///
+/// Rust cannot check whether two types are not the same, so this trait relies on type inference:
+/// the head of the list must be retrieved [by its type](Get) in exactly one way,
+/// otherwise the index of the head cannot be inferred and compilation fails.
+///
+/// Note that this check only holds where the index is inferred.
+/// This trait is implemented for lists with repeated types of elements too,
+/// so generic code which specifies the index explicitly bypasses the check.
+/// This means index generic parameter should always be inferred by the compiler
+/// and never specified explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use hlist2::{HList, ops::{ManyIndex, Unique}};
+///
+/// fn assert_unique<L, I>()
+/// where
+///     L: Unique<I>,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// assert_unique::<HList![], _>();
+/// assert_unique::<HList![i32, f64, bool, &str], _>();
+/// ```
+///
+/// List with a repeated type of element is not unique:
+///
+/// ```compile_fail
+/// use hlist2::{HList, ops::{ManyIndex, Unique}};
+///
+/// fn assert_unique<L, I>()
+/// where
+///     L: Unique<I>,
+///     I: ManyIndex,
+/// {
+/// }
+///
+/// assert_unique::<HList![i32, f64, i32], _>();
+/// ```
+pub trait Unique<I>: HList
+where
+    I: ManyIndex,
+{
+}
+
+impl Unique<Nil> for Nil {}
+
+impl<Head, Tail, IndexHead, IndexTail> Unique<Cons<IndexHead, IndexTail>> for Cons<Head, Tail>
+where
+    Self: Get<Head, IndexHead>,
+    Tail: Unique<IndexTail>,
+    IndexHead: Index,
+    IndexTail: ManyIndex,
+{
+}
@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+
+use crate::{Cons, HList, Nil};
+
+/// Fallibly transform one heterogenous list into another, collecting all the errors.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait Validate<Mapper, Error>: HList {
+    /// Type of new heterogenous list after successful transformation.
+    type Output: HList;
+
+    /// Fallibly transforms the heterogenous list into another heterogenous list
+    /// by applying an operation to each element by mapper.
+    ///
+    /// Unlike [`TryMapRef::try_map_ref()`][try_map_ref], transformation is not stopped
+    /// on the first error: every element is visited, and all the errors
+    /// are collected into a [vector](Vec) in the order of elements.
+    ///
+    /// [try_map_ref]: crate::ops::TryMapRef::try_map_ref()
+    ///
+    /// # Examples
+    ///
+    /// You can validate the list if it is homogenous (all elements have the same type):
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Validate};
+    ///
+    /// let list = hlist!["1", "2", "3"];
+    /// let parsed = list.validate(|s: &str| s.parse::<i32>());
+    /// assert_eq!(parsed, Ok(hlist![1, 2, 3]));
+    ///
+    /// let list = hlist!["1", "x", "3"];
+    /// let parsed = list.validate(|s: &str| s.parse::<i32>());
+    /// assert_eq!(parsed.unwrap_err().len(), 1);
+    ///
+    /// let list = hlist!["1", "x", "y"];
+    /// let parsed = list.validate(|s: &str| s.parse::<i32>());
+    /// assert_eq!(parsed.unwrap_err().len(), 2);
+    /// ```
+    ///
+    /// Validation of heterogenous list is possible with heterogenous list of closures as mapper:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Validate};
+    ///
+    /// let list = hlist![String::from("name"), -1, 200.0];
+    /// let validated = list.validate(
+    ///     hlist![
+    ///         |name: String| if name.is_empty() { Err("empty name") } else { Ok(name) },
+    ///         |age: i32| u32::try_from(age).map_err(|_| "negative age"),
+    ///         |height: f64| if height < 300.0 { Ok(height) } else { Err("too tall") },
+    ///     ],
+    /// );
+    /// assert_eq!(validated, Err(vec!["negative age"]));
+    /// ```
+    fn validate(self, mapper: Mapper) -> Result<Self::Output, Vec<Error>>;
+}
+
+impl<T, M, E> Validate<M, E> for T
+where
+    T: ValidateInto<M, E>,
+{
+    type Output = T::Output;
+
+    fn validate(self, mapper: M) -> Result<Self::Output, Vec<E>> {
+        let mut errors = Vec::new();
+        match self.validate_into(mapper, &mut errors) {
+            Some(output) if errors.is_empty() => Ok(output),
+            _ => Err(errors),
+        }
+    }
+}
+
+pub trait ValidateInto<Mapper, Error>: HList {
+    type Output: HList;
+
+    fn validate_into(self, mapper: Mapper, errors: &mut Vec<Error>) -> Option<Self::Output>;
+}
+
+impl<M, E> ValidateInto<M, E> for Nil {
+    type Output = Nil;
+
+    fn validate_into(self, _: M, _: &mut Vec<E>) -> Option<Self::Output> {
+        Some(self)
+    }
+}
+
+impl<M, E, R, Head, Tail> ValidateInto<M, E> for Cons<Head, Tail>
+where
+    M: FnMut(Head) -> Result<R, E>,
+    Tail: ValidateInto<M, E>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn validate_into(self, mut mapper: M, errors: &mut Vec<E>) -> Option<Self::Output> {
+        let Cons(head, tail) = self;
+        let head = mapper(head).map_err(|error| errors.push(error)).ok();
+        let tail = tail.validate_into(mapper, errors);
+        Some(Cons(head?, tail?))
+    }
+}
+
+impl<MHead, MTail, E, R, Head, Tail> ValidateInto<Cons<MHead, MTail>, E> for Cons<Head, Tail>
+where
+    MHead: FnOnce(Head) -> Result<R, E>,
+    Tail: ValidateInto<MTail, E>,
+{
+    type Output = Cons<R, Tail::Output>;
+
+    fn validate_into(
+        self,
+        mapper: Cons<MHead, MTail>,
+        errors: &mut Vec<E>,
+    ) -> Option<Self::Output> {
+        let Cons(head, tail) = self;
+        let Cons(mapper_head, mapper_tail) = mapper;
+        let head = mapper_head(head).map_err(|error| errors.push(error)).ok();
+        let tail = tail.validate_into(mapper_tail, errors);
+        Some(Cons(head?, tail?))
+    }
+}
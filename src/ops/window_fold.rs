@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+
+use crate::{Cons, HList, Len};
+
+/// Fold each sliding window of the homogenous list.
+///
+/// This trait is available only with `alloc` feature enabled.
+pub trait WindowFold<T>: Len {
+    /// Folds each window of `W` adjacent elements of the homogenous list
+    /// into an accumulator, starting from the clone of initial value for each window.
+    ///
+    /// Resulting vector contains `LEN - W + 1` accumulators in order of windows,
+    /// or no accumulators at all if `W` is greater than length of the list.
+    /// Window size of zero is rejected at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::WindowFold};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// assert_eq!(list.window_fold::<2, _, _>(0, |a, &x| a + x), vec![3, 5, 7]);
+    /// assert_eq!(list.window_fold::<1, _, _>(0, |a, &x| a + x), vec![1, 2, 3, 4]);
+    /// assert_eq!(list.window_fold::<4, _, _>(0, |a, &x| a + x), vec![10]);
+    /// assert_eq!(list.window_fold::<5, _, _>(0, |a, &x| a + x), vec![]);
+    /// ```
+    ///
+    /// Window must not be empty:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::WindowFold};
+    ///
+    /// let list = hlist![1, 2, 3, 4];
+    /// let sums = list.window_fold::<0, _, _>(0, |a, &x| a + x);
+    /// ```
+    fn window_fold<const W: usize, A, F>(&self, init: A, f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: FnMut(A, &T) -> A;
+}
+
+impl<Head, Tail> WindowFold<Head> for Cons<Head, Tail>
+where
+    Tail: HList,
+    Self: Len,
+    for<'a> &'a Self: IntoIterator<Item = &'a Head>,
+{
+    fn window_fold<const W: usize, A, F>(&self, init: A, mut f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: FnMut(A, &Head) -> A,
+    {
+        const { assert!(W > 0, "window size must be non-zero") };
+
+        let items: Vec<_> = self.into_iter().collect();
+        items
+            .windows(W)
+            .map(|window| window.iter().fold(init.clone(), |acc, item| f(acc, item)))
+            .collect()
+    }
+}
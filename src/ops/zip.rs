@@ -1,4 +1,4 @@
-use crate::{Cons, HList, Nil};
+use crate::{convert::IntoHList, Cons, HList, Nil};
 
 /// Merge two heterogenous lists into a single heterogenous list of pairs.
 pub trait Zip<Other>: HList
@@ -53,3 +53,122 @@ where
         Cons(head, tail)
     }
 }
+
+/// Merge heterogenous list and a tuple into a single heterogenous list of pairs.
+pub trait ZipTuple<Tuple>: HList
+where
+    Tuple: IntoHList,
+{
+    /// Type of new heterogenous list after merging.
+    type Output: HList;
+
+    /// Merges, or 'zips up' heterogenous list and a tuple into a single heterogenous list of pairs.
+    ///
+    /// This is the same as [`Zip::zip`][zip] method,
+    /// but there is no need to convert a tuple into heterogenous list first.
+    ///
+    /// [zip]: crate::ops::Zip::zip()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ZipTuple};
+    ///
+    /// let zipped = hlist![1, 2].zip_tuple((10, 20));
+    /// assert_eq!(zipped, hlist![(1, 10), (2, 20)]);
+    ///
+    /// let zipped = hlist![1, 2.0, true].zip_tuple(("a", 'b', ()));
+    /// assert_eq!(zipped, hlist![(1, "a"), (2.0, 'b'), (true, ())]);
+    ///
+    /// let zipped = hlist![].zip_tuple(());
+    /// assert_eq!(zipped, hlist![]);
+    /// ```
+    ///
+    /// Length of the tuple must be equal to the length of the list:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::ZipTuple};
+    ///
+    /// let zipped = hlist![1, 2].zip_tuple((10, 20, 30));
+    /// ```
+    fn zip_tuple(self, tuple: Tuple) -> Self::Output;
+}
+
+impl<T, Tuple> ZipTuple<Tuple> for T
+where
+    T: Zip<Tuple::HList>,
+    Tuple: IntoHList,
+{
+    type Output = T::Output;
+
+    fn zip_tuple(self, tuple: Tuple) -> Self::Output {
+        let other = tuple.into_hlist();
+        self.zip(other)
+    }
+}
+
+/// Merge references of elements of two heterogenous lists
+/// into a single heterogenous list of pairs.
+pub trait ZipRef<Other>: HList
+where
+    Other: HList,
+{
+    /// Type of new heterogenous list of pairs of references after merging.
+    type Output<'a>: HList
+    where
+        Self: 'a,
+        Other: 'a;
+
+    /// Merges, or 'zips up' references of elements of two heterogenous lists
+    /// into a single heterogenous list of pairs.
+    ///
+    /// This is the same as [`Zip::zip`][zip] method,
+    /// but both heterogenous lists are left intact.
+    ///
+    /// [zip]: crate::ops::Zip::zip()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ZipRef};
+    ///
+    /// let first = hlist![1, 2.0, String::from("three")];
+    /// let second = hlist!['a', true, ()];
+    ///
+    /// let zipped = first.zip_ref(&second);
+    /// assert_eq!(zipped, hlist![(&1, &'a'), (&2.0, &true), (&String::from("three"), &())]);
+    ///
+    /// assert_eq!(first, hlist![1, 2.0, String::from("three")]);
+    /// assert_eq!(second, hlist!['a', true, ()]);
+    /// ```
+    fn zip_ref<'a>(&'a self, other: &'a Other) -> Self::Output<'a>;
+}
+
+impl ZipRef<Nil> for Nil {
+    type Output<'a> = Nil;
+
+    fn zip_ref<'a>(&'a self, _: &'a Nil) -> Self::Output<'a> {
+        *self
+    }
+}
+
+impl<Head, Tail, OHead, OTail> ZipRef<Cons<OHead, OTail>> for Cons<Head, Tail>
+where
+    Tail: ZipRef<OTail>,
+    OTail: HList,
+{
+    type Output<'a>
+        = Cons<(&'a Head, &'a OHead), Tail::Output<'a>>
+    where
+        Self: 'a,
+        Cons<OHead, OTail>: 'a;
+
+    fn zip_ref<'a>(&'a self, other: &'a Cons<OHead, OTail>) -> Self::Output<'a> {
+        let Cons(head, tail) = self;
+        let Cons(other_head, other_tail) = other;
+
+        let head = (head, other_head);
+        let tail = tail.zip_ref(other_tail);
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{Cons, HList, Nil};
+
+pub trait ZipEach<T>: HList {
+    fn zip_with(self, other: Self, op: fn(T, T) -> T) -> Self;
+}
+
+impl<T> ZipEach<T> for Nil {
+    fn zip_with(self, _: Self, _: fn(T, T) -> T) -> Self {
+        self
+    }
+}
+
+impl<T, Tail> ZipEach<T> for Cons<T, Tail>
+where
+    Tail: ZipEach<T>,
+{
+    fn zip_with(self, other: Self, op: fn(T, T) -> T) -> Self {
+        let Cons(head, tail) = self;
+        let Cons(other_head, other_tail) = other;
+
+        let head = op(head, other_head);
+        let tail = tail.zip_with(other_tail, op);
+        Cons(head, tail)
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Cons, HList, Nil};
+
+/// Fold every pair of elements of two heterogenous lists into an accumulator.
+pub trait ZipFold<Other, Accumulator, Folder>: HList
+where
+    Other: HList,
+{
+    /// Folds every pair of elements of two heterogenous lists into an accumulator
+    /// by applying an operation via folder, returning the final result.
+    ///
+    /// This is the same as zipping two lists with [`Zip::zip()`][zip]
+    /// and folding the result with [`Fold::fold()`][fold],
+    /// but without building an intermediate list of pairs.
+    /// Both lists must have the same length.
+    ///
+    /// [zip]: crate::ops::Zip::zip()
+    /// [fold]: crate::ops::Fold::fold()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ZipFold};
+    ///
+    /// let first = hlist![1, 2, 3];
+    /// let second = hlist![4, 5, 6];
+    ///
+    /// let dot = first.zip_fold(second, 0, |acc, x, y| acc + x * y);
+    /// assert_eq!(dot, 32);
+    /// ```
+    ///
+    /// Folding of heterogenous lists is possible with heterogenous list of closures as folder:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ZipFold};
+    ///
+    /// let first = hlist![1, "hello"];
+    /// let second = hlist![2.0, 'w'];
+    ///
+    /// let folded = first.zip_fold(
+    ///     second,
+    ///     String::new(),
+    ///     hlist![
+    ///         |acc, i: i32, f: f64| format!("{acc}{}", i as f64 * f),
+    ///         |acc, s: &str, c: char| format!("{acc} {s} {c}"),
+    ///     ],
+    /// );
+    /// assert_eq!(folded, "2 hello w");
+    /// ```
+    ///
+    /// Lists must have the same length:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::ZipFold};
+    ///
+    /// let first = hlist![1, 2, 3];
+    /// let second = hlist![4, 5];
+    ///
+    /// let dot = first.zip_fold(second, 0, |acc, x, y| acc + x * y);
+    /// ```
+    fn zip_fold(self, other: Other, init: Accumulator, folder: Folder) -> Accumulator;
+}
+
+impl<A, F> ZipFold<Nil, A, F> for Nil {
+    fn zip_fold(self, _: Nil, init: A, _: F) -> A {
+        init
+    }
+}
+
+impl<A, F, Head, Tail, OHead, OTail> ZipFold<Cons<OHead, OTail>, A, F> for Cons<Head, Tail>
+where
+    F: FnMut(A, Head, OHead) -> A,
+    Tail: ZipFold<OTail, A, F>,
+    OTail: HList,
+{
+    fn zip_fold(self, other: Cons<OHead, OTail>, init: A, mut folder: F) -> A {
+        let Cons(head, tail) = self;
+        let Cons(other_head, other_tail) = other;
+        let init = folder(init, head, other_head);
+        tail.zip_fold(other_tail, init, folder)
+    }
+}
+
+impl<A, FHead, FTail, Head, Tail, OHead, OTail> ZipFold<Cons<OHead, OTail>, A, Cons<FHead, FTail>>
+    for Cons<Head, Tail>
+where
+    FHead: FnOnce(A, Head, OHead) -> A,
+    Tail: ZipFold<OTail, A, FTail>,
+    OTail: HList,
+{
+    fn zip_fold(self, other: Cons<OHead, OTail>, init: A, folder: Cons<FHead, FTail>) -> A {
+        let Cons(head, tail) = self;
+        let Cons(other_head, other_tail) = other;
+        let Cons(folder_head, folder_tail) = folder;
+        let init = folder_head(init, head, other_head);
+        tail.zip_fold(other_tail, init, folder_tail)
+    }
+}
+
+/// Fold every triple of elements of three heterogenous lists into an accumulator.
+pub trait Zip3Fold<Second, Third, Accumulator, Folder>: HList
+where
+    Second: HList,
+    Third: HList,
+{
+    /// Folds every triple of elements of three heterogenous lists into an accumulator
+    /// by applying an operation via folder, returning the final result.
+    ///
+    /// This is the same as [`ZipFold::zip_fold()`], but three lists are walked together.
+    /// All the lists must have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Zip3Fold};
+    ///
+    /// let a = hlist![1, 2, 3];
+    /// let b = hlist![4, 5, 6];
+    /// let c = hlist![7, 8, 9];
+    ///
+    /// let sum = a.zip3_fold(b, c, 0, |acc, x, y, z| acc + x * y * z);
+    /// assert_eq!(sum, 1 * 4 * 7 + 2 * 5 * 8 + 3 * 6 * 9);
+    /// ```
+    ///
+    /// Folding of heterogenous lists is possible with heterogenous list of closures as folder:
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::Zip3Fold};
+    ///
+    /// let a = hlist![1, "hello"];
+    /// let b = hlist![2.0, ' '];
+    /// let c = hlist![3_u8, "world"];
+    ///
+    /// let folded = a.zip3_fold(
+    ///     b,
+    ///     c,
+    ///     String::new(),
+    ///     hlist![
+    ///         |acc, i: i32, f: f64, u: u8| format!("{acc}{};", i as f64 * f * u as f64),
+    ///         |acc, s: &str, c: char, t: &str| format!("{acc}{s}{c}{t}"),
+    ///     ],
+    /// );
+    /// assert_eq!(folded, "6;hello world");
+    /// ```
+    ///
+    /// Lists must have the same length:
+    ///
+    /// ```compile_fail
+    /// use hlist2::{hlist, ops::Zip3Fold};
+    ///
+    /// let a = hlist![1, 2, 3];
+    /// let b = hlist![4, 5, 6];
+    /// let c = hlist![7, 8];
+    ///
+    /// let sum = a.zip3_fold(b, c, 0, |acc, x, y, z| acc + x * y * z);
+    /// ```
+    fn zip3_fold(
+        self,
+        second: Second,
+        third: Third,
+        init: Accumulator,
+        folder: Folder,
+    ) -> Accumulator;
+}
+
+impl<A, F> Zip3Fold<Nil, Nil, A, F> for Nil {
+    fn zip3_fold(self, _: Nil, _: Nil, init: A, _: F) -> A {
+        init
+    }
+}
+
+impl<A, F, Head, Tail, BHead, BTail, CHead, CTail>
+    Zip3Fold<Cons<BHead, BTail>, Cons<CHead, CTail>, A, F> for Cons<Head, Tail>
+where
+    F: FnMut(A, Head, BHead, CHead) -> A,
+    Tail: Zip3Fold<BTail, CTail, A, F>,
+    BTail: HList,
+    CTail: HList,
+{
+    fn zip3_fold(
+        self,
+        second: Cons<BHead, BTail>,
+        third: Cons<CHead, CTail>,
+        init: A,
+        mut folder: F,
+    ) -> A {
+        let Cons(head, tail) = self;
+        let Cons(second_head, second_tail) = second;
+        let Cons(third_head, third_tail) = third;
+        let init = folder(init, head, second_head, third_head);
+        tail.zip3_fold(second_tail, third_tail, init, folder)
+    }
+}
+
+impl<A, FHead, FTail, Head, Tail, BHead, BTail, CHead, CTail>
+    Zip3Fold<Cons<BHead, BTail>, Cons<CHead, CTail>, A, Cons<FHead, FTail>> for Cons<Head, Tail>
+where
+    FHead: FnOnce(A, Head, BHead, CHead) -> A,
+    Tail: Zip3Fold<BTail, CTail, A, FTail>,
+    BTail: HList,
+    CTail: HList,
+{
+    fn zip3_fold(
+        self,
+        second: Cons<BHead, BTail>,
+        third: Cons<CHead, CTail>,
+        init: A,
+        folder: Cons<FHead, FTail>,
+    ) -> A {
+        let Cons(head, tail) = self;
+        let Cons(second_head, second_tail) = second;
+        let Cons(third_head, third_tail) = third;
+        let Cons(folder_head, folder_tail) = folder;
+        let init = folder_head(init, head, second_head, third_head);
+        tail.zip3_fold(second_tail, third_tail, init, folder_tail)
+    }
+}
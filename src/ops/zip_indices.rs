@@ -0,0 +1,43 @@
+use crate::HList;
+
+use super::MapIndexed;
+
+/// Pair each element of the homogenous list with its index.
+pub trait ZipIndices<T>: HList {
+    /// Type of new homogenous list of index-value pairs.
+    type Output: HList;
+
+    /// Pairs each element of the homogenous list with its index,
+    /// producing new homogenous list of `(usize, T)` pairs.
+    ///
+    /// Unlike [`MapIndexed`], resulting list is guaranteed to be homogenous,
+    /// so it is ready to be iterated over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, ops::ZipIndices};
+    ///
+    /// let list = hlist![10, 20, 30];
+    /// let list = list.zip_indices();
+    /// assert_eq!(list, hlist![(0, 10), (1, 20), (2, 30)]);
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next(), Some((0, 10)));
+    /// assert_eq!(iter.next(), Some((1, 20)));
+    /// assert_eq!(iter.next(), Some((2, 30)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn zip_indices(self) -> Self::Output;
+}
+
+impl<T, L> ZipIndices<T> for L
+where
+    L: MapIndexed<fn(usize, T) -> (usize, T)>,
+{
+    type Output = L::Output;
+
+    fn zip_indices(self) -> Self::Output {
+        self.map_indexed(|index, item| (index, item))
+    }
+}
@@ -0,0 +1,255 @@
+//! Interoperability of heterogenous lists with [tuples](prim@tuple).
+//!
+//! Tuples can be converted into heterogenous lists with [`IntoHList`] trait,
+//! so some operations defined in this module convert tuples first
+//! and then apply some operation to the resulting heterogenous list.
+//! Other operations convert heterogenous lists into tuples of some other shape, and vice versa.
+
+use crate::{convert::IntoHList, ops::Map, HList};
+
+/// Construct heterogenous list by transforming elements of the tuple.
+pub trait MapFromTuple<Mapper>: IntoHList {
+    /// Type of new heterogenous list after transformation.
+    type Output: HList;
+
+    /// Converts the tuple into heterogenous list and then transforms it
+    /// into another heterogenous list by applying an operation to each element by mapper.
+    ///
+    /// This is the same as calling [`IntoHList::into_hlist()`] and then [`Map::map()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, tuple::MapFromTuple};
+    ///
+    /// let tuple = (1, 2.0);
+    /// let list = tuple.map_from_tuple(hlist![|x| x + 1, |y| y * 2.0]);
+    /// assert_eq!(list, hlist![2, 4.0]);
+    ///
+    /// let tuple = (1, 2.0, "3");
+    /// let list = tuple.map_from_tuple(
+    ///     hlist![
+    ///         |x: i32| x.to_string(),
+    ///         |y: f64| y > 0.0,
+    ///         |z: &str| z.len(),
+    ///     ],
+    /// );
+    /// assert_eq!(list, hlist![String::from("1"), true, 1]);
+    /// ```
+    ///
+    /// Tuple can be mapped with one closure if it is homogenous:
+    ///
+    /// ```
+    /// use hlist2::{hlist, tuple::MapFromTuple};
+    ///
+    /// let tuple = (1, 2, 3);
+    /// let list = tuple.map_from_tuple(|x| x * 10);
+    /// assert_eq!(list, hlist![10, 20, 30]);
+    /// ```
+    fn map_from_tuple(self, mapper: Mapper) -> Self::Output;
+}
+
+impl<T, M> MapFromTuple<M> for T
+where
+    T: IntoHList,
+    T::HList: Map<M>,
+{
+    type Output = <T::HList as Map<M>>::Output;
+
+    fn map_from_tuple(self, mapper: M) -> Self::Output {
+        let list = self.into_hlist();
+        list.map(mapper)
+    }
+}
+
+/// Convert heterogenous list into a tuple of [`Option`]s.
+pub trait IntoOptionTuple: HList {
+    /// Type of the tuple with each element of the heterogenous list wrapped in [`Option`].
+    type Output;
+
+    /// Converts heterogenous list into a tuple of [`Option`]s,
+    /// wrapping each element of the list into [`Some`].
+    ///
+    /// This trait is implemented for heterogenous lists of size 12 and less.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, tuple::IntoOptionTuple};
+    ///
+    /// let tuple = hlist![1, 2.0].into_option_tuple();
+    /// assert_eq!(tuple, (Some(1), Some(2.0)));
+    ///
+    /// let tuple = hlist![1, 2.0, true, "hello world"].into_option_tuple();
+    /// assert_eq!(tuple, (Some(1), Some(2.0), Some(true), Some("hello world")));
+    /// ```
+    fn into_option_tuple(self) -> Self::Output;
+}
+
+/// Collapse a tuple of [`Option`]s into an optional heterogenous list.
+pub trait CollapseOptions {
+    /// Type of the heterogenous list with values of each [`Option`] of the tuple.
+    type Output: HList;
+
+    /// Collapses a tuple of [`Option`]s into an optional heterogenous list.
+    ///
+    /// Returns [`Some`] only if every element of the tuple is [`Some`], or [`None`] otherwise.
+    /// This is the inverse of [`IntoOptionTuple::into_option_tuple()`].
+    ///
+    /// This trait is implemented for tuples of size 12 and less.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, tuple::CollapseOptions};
+    ///
+    /// let list = (Some(1), Some(2.0)).collapse();
+    /// assert_eq!(list, Some(hlist![1, 2.0]));
+    ///
+    /// let list = (Some(1), None::<f64>, Some(true)).collapse();
+    /// assert_eq!(list, None);
+    ///
+    /// let list = ().collapse();
+    /// assert_eq!(list, Some(hlist![]));
+    /// ```
+    fn collapse(self) -> Option<Self::Output>;
+}
+
+macro_rules! option_tuple {
+    ($($types:ident),*) => {
+        impl<$($types),*> IntoOptionTuple for $crate::HList!($($types,)*) {
+            type Output = ($(Option<$types>,)*);
+
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn into_option_tuple(self) -> Self::Output {
+                let $crate::hlist!($($types,)*) = self;
+                ($(Some($types),)*)
+            }
+        }
+
+        impl<$($types),*> CollapseOptions for ($(Option<$types>,)*) {
+            type Output = $crate::HList!($($types,)*);
+
+            #[allow(non_snake_case)]
+            fn collapse(self) -> Option<Self::Output> {
+                let ($($types,)*) = self;
+                Some($crate::hlist!($($types?,)*))
+            }
+        }
+    };
+}
+
+// Conversion with tuples of options is implemented for tuples of size 12 and less
+option_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+option_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+option_tuple!(A, B, C, D, E, F, G, H, I, J);
+option_tuple!(A, B, C, D, E, F, G, H, I);
+option_tuple!(A, B, C, D, E, F, G, H);
+option_tuple!(A, B, C, D, E, F, G);
+option_tuple!(A, B, C, D, E, F);
+option_tuple!(A, B, C, D, E);
+option_tuple!(A, B, C, D);
+option_tuple!(A, B, C);
+option_tuple!(A, B);
+option_tuple!(A);
+option_tuple!();
+
+/// Convert a tuple of references into heterogenous list of references.
+pub trait RefTupleToHList<'a> {
+    /// Type of heterogenous list of references.
+    type Output: HList;
+
+    /// Converts a tuple of references into heterogenous list of references
+    /// without cloning referenced values.
+    ///
+    /// This is the same as [`IntoHList::into_hlist()`], but can be applied
+    /// only to tuples of references, so the lifetime of references is preserved explicitly.
+    ///
+    /// This trait is implemented for tuples of size 12 and less.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, tuple::RefTupleToHList, Cons, Nil};
+    ///
+    /// let (a, b) = (1, 2.0);
+    /// let list: Cons<&i32, Cons<&f64, Nil>> = (&a, &b).ref_tuple_to_hlist();
+    /// assert_eq!(list, hlist![&1, &2.0]);
+    ///
+    /// let (a, b, c) = (String::from("hello"), [1, 2, 3], true);
+    /// let list = (&a, &b, &c).ref_tuple_to_hlist();
+    /// assert_eq!(list, hlist![&String::from("hello"), &[1, 2, 3], &true]);
+    /// ```
+    fn ref_tuple_to_hlist(self) -> Self::Output;
+}
+
+/// Convert a tuple of mutable references into heterogenous list of mutable references.
+pub trait MutTupleToHList<'a> {
+    /// Type of heterogenous list of mutable references.
+    type Output: HList;
+
+    /// Converts a tuple of mutable references into heterogenous list of mutable references.
+    ///
+    /// This is the same as [`IntoHList::into_hlist()`], but can be applied
+    /// only to tuples of mutable references, so the lifetime of references is preserved explicitly.
+    ///
+    /// This trait is implemented for tuples of size 12 and less.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hlist2::{hlist, tuple::MutTupleToHList};
+    ///
+    /// let (mut a, mut b) = (1, 2.0);
+    /// let hlist![a_ref, b_ref] = (&mut a, &mut b).mut_tuple_to_hlist();
+    /// *a_ref += 1;
+    /// *b_ref *= 2.0;
+    /// assert_eq!((a, b), (2, 4.0));
+    /// ```
+    fn mut_tuple_to_hlist(self) -> Self::Output;
+}
+
+macro_rules! ref_tuple {
+    ($($types:ident),*) => {
+        impl<'a, $($types),*> RefTupleToHList<'a> for ($(&'a $types,)*)
+        where
+            $($types: ?Sized,)*
+        {
+            type Output = $crate::HList!($(&'a $types,)*);
+
+            #[allow(non_snake_case)]
+            fn ref_tuple_to_hlist(self) -> Self::Output {
+                let ($($types,)*) = self;
+                $crate::hlist!($($types,)*)
+            }
+        }
+
+        impl<'a, $($types),*> MutTupleToHList<'a> for ($(&'a mut $types,)*)
+        where
+            $($types: ?Sized,)*
+        {
+            type Output = $crate::HList!($(&'a mut $types,)*);
+
+            #[allow(non_snake_case)]
+            fn mut_tuple_to_hlist(self) -> Self::Output {
+                let ($($types,)*) = self;
+                $crate::hlist!($($types,)*)
+            }
+        }
+    };
+}
+
+// Conversion from tuple of references is implemented for tuples of size 12 and less
+ref_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+ref_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+ref_tuple!(A, B, C, D, E, F, G, H, I, J);
+ref_tuple!(A, B, C, D, E, F, G, H, I);
+ref_tuple!(A, B, C, D, E, F, G, H);
+ref_tuple!(A, B, C, D, E, F, G);
+ref_tuple!(A, B, C, D, E, F);
+ref_tuple!(A, B, C, D, E);
+ref_tuple!(A, B, C, D);
+ref_tuple!(A, B, C);
+ref_tuple!(A, B);
+ref_tuple!(A);
+ref_tuple!();